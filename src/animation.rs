@@ -0,0 +1,183 @@
+//! Frame-based style animations.
+//!
+//! An animation moves a node's inline style towards a target over a duration.
+//! Numeric fields (`x`, `y`, `width`, `height`, `gap`, `padding`, `z_index`) and
+//! colors (`fg_color`, `bg_color`, `border_color`, `border_focus_color`) are
+//! interpolated every frame; every other field switches to the target at once.
+//!
+//! ```no_run
+//! # use ccui::style::Color;
+//! # use ccui::{Animation, Container, Easing, EventType, Style};
+//! # use std::time::Duration;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let mut doc = ccui::Ui::run()?;
+//! # let panel = doc.add_container("panel", Style::new())?;
+//! panel.animate(|s| s.x = 40, Duration::from_millis(300), Easing::EaseOut)?;
+//!
+//! doc.add_event_listener("btn", EventType::MouseEnter, |ctx| {
+//!     let fade = Animation::new(|s| s.bg_color = Some(Color::Rgb(60, 60, 90)), Duration::from_millis(150));
+//!     ctx.ui().animate(&ctx.target_id, fade);
+//! })?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::style::{Color, RectOffset, Style};
+use crate::stylesheet::Declaration;
+use crate::theme::Theme;
+use crate::timer::{self, TimerCallback, TimerContext};
+use std::time::Duration;
+
+/// Easing curve mapping linear progress (0..=1) to eased progress.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Easing {
+    Linear,
+    /// Starts slow (quadratic).
+    EaseIn,
+    /// Ends slow (quadratic).
+    EaseOut,
+    /// Starts and ends slow (cubic).
+    #[default]
+    EaseInOut,
+    /// Ends with a few decaying bounces.
+    Bounce,
+    /// Any curve with `f(0) = 0` and `f(1) = 1`.
+    Custom(fn(f32) -> f32),
+}
+
+impl Easing {
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => t * (2.0 - t),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            Easing::Bounce => bounce(t),
+            Easing::Custom(f) => f(t),
+        }
+    }
+}
+
+fn bounce(t: f32) -> f32 {
+    const N: f32 = 7.5625;
+    const D: f32 = 2.75;
+    if t < 1.0 / D {
+        N * t * t
+    } else if t < 2.0 / D {
+        let t = t - 1.5 / D;
+        N * t * t + 0.75
+    } else if t < 2.5 / D {
+        let t = t - 2.25 / D;
+        N * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D;
+        N * t * t + 0.984375
+    }
+}
+
+/// A style change spread over time.
+pub struct Animation {
+    pub(crate) apply: Box<dyn FnOnce(&mut Style) + Send>,
+    pub(crate) duration: Duration,
+    pub(crate) easing: Easing,
+    pub(crate) on_complete: Option<TimerCallback>,
+}
+
+impl Animation {
+    /// Animate from the node's current style to the style after `f`.
+    pub fn new<F>(f: F, duration: Duration) -> Self
+    where
+        F: FnOnce(&mut Style) + Send + 'static,
+    {
+        Animation {
+            apply: Box::new(f),
+            duration,
+            easing: Easing::default(),
+            on_complete: None,
+        }
+    }
+
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Run `f` in the render loop once the animation reaches its target.
+    /// Not called if the animation is cancelled or replaced.
+    pub fn on_complete<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&TimerContext) + Send + 'static,
+    {
+        self.on_complete = Some(timer::once(f));
+        self
+    }
+}
+
+/// The style `t` (eased, 0..=1) of the way from `from` to `to`.
+pub(crate) fn interpolate(from: &Style, to: &Style, t: f32, theme: &Theme) -> Style {
+    let mut style = to.clone();
+    style.x = lerp_u16(from.x, to.x, t);
+    style.y = lerp_u16(from.y, to.y, t);
+    style.width = lerp_u16(from.width, to.width, t);
+    style.height = lerp_u16(from.height, to.height, t);
+    style.gap = lerp_u16(from.gap, to.gap, t);
+    style.z_index = lerp(from.z_index as f32, to.z_index as f32, t).round() as i32;
+    style.padding = RectOffset {
+        top: lerp_u16(from.padding.top, to.padding.top, t),
+        right: lerp_u16(from.padding.right, to.padding.right, t),
+        bottom: lerp_u16(from.padding.bottom, to.padding.bottom, t),
+        left: lerp_u16(from.padding.left, to.padding.left, t),
+    };
+    style.fg_color = lerp_color(from.fg_color, to.fg_color, t, theme);
+    style.bg_color = lerp_color(from.bg_color, to.bg_color, t, theme);
+    style.border_color = lerp_color(from.border_color, to.border_color, t, theme);
+    style.border_focus_color = lerp_color(from.border_focus_color, to.border_focus_color, t, theme);
+    style
+}
+
+/// Copy the properties an animation changes (those that differ between
+/// `from` and `to`) from `frame` into `style`, leaving the others alone.
+pub(crate) fn apply_changed(style: &mut Style, from: &Style, to: &Style, frame: &Style) {
+    let from = Declaration::all(from);
+    let to = Declaration::all(to);
+    for ((from, to), value) in from.iter().zip(&to).zip(Declaration::all(frame)) {
+        if from != to {
+            value.apply(style);
+        }
+    }
+}
+
+fn lerp(from: f32, to: f32, t: f32) -> f32 {
+    from + (to - from) * t
+}
+
+fn lerp_u16(from: u16, to: u16, t: f32) -> u16 {
+    // Custom curves may overshoot the range
+    lerp(from as f32, to as f32, t)
+        .round()
+        .clamp(0.0, u16::MAX as f32) as u16
+}
+
+/// Blend two colors in RGB; colors without an RGB value switch at once.
+fn lerp_color(from: Option<Color>, to: Option<Color>, t: f32, theme: &Theme) -> Option<Color> {
+    let rgb = |color: Option<Color>| color.and_then(|c| c.resolve(theme).to_rgb());
+    match (rgb(from), rgb(to)) {
+        (Some(from), Some(to)) => {
+            let channel =
+                |a: u8, b: u8| lerp(a as f32, b as f32, t).round().clamp(0.0, 255.0) as u8;
+            Some(Color::Rgb(
+                channel(from.0, to.0),
+                channel(from.1, to.1),
+                channel(from.2, to.2),
+            ))
+        }
+        _ => to,
+    }
+}
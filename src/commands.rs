@@ -0,0 +1,197 @@
+//! UI commands queued from event listeners.
+//!
+//! Listeners run inside the render loop, so they can't wait on the `Document`.
+//! Instead they queue changes through `ctx.ui()`; the render loop applies them
+//! once dispatch is done, before the next frame is drawn. Queueing wakes the
+//! loop, so changes made from spawned tasks show up without further input.
+//!
+//! ```no_run
+//! # use ccui::EventType;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let doc = ccui::Ui::run()?;
+//! doc.add_event_listener("save", EventType::Click, |ctx| {
+//!     ctx.ui().set_content("status", "Saved");
+//!     ctx.ui().focus("editor");
+//! })?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::animation::Animation;
+use crate::event::{CustomEvent, UiMessage, WidgetMessage};
+use crate::timer::{self, TimerContext, TimerId, TimerKind};
+use crate::widget::{Widget, input::InputMessage, text::TextMessage, textarea::TextareaMessage};
+use parking_lot::Mutex;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Notify;
+
+/// Queue of UI changes shared by the render loop and event contexts.
+#[derive(Clone, Default)]
+pub struct UiCommands {
+    queue: Arc<Mutex<Vec<UiMessage>>>,
+    /// Wakes the render loop when something is queued.
+    queued: Arc<Notify>,
+}
+
+impl UiCommands {
+    /// Queue any UI message.
+    pub fn send(&self, msg: UiMessage) {
+        self.queue.lock().push(msg);
+        self.queued.notify_one();
+    }
+
+    /// Set the content of a `Text` widget.
+    pub fn set_content(&self, id: impl Into<String>, content: impl Into<String>) {
+        self.send(UiMessage::WidgetMessage {
+            id: id.into(),
+            message: Box::new(TextMessage::SetContent(content.into())),
+        });
+    }
+
+    /// Set the value of an `Input` or `Textarea` widget.
+    pub fn set_value(&self, id: impl Into<String>, value: impl Into<String>) {
+        self.send(UiMessage::WidgetMessage {
+            id: id.into(),
+            message: Box::new(SetValue(value.into())),
+        });
+    }
+
+    pub fn focus(&self, id: impl Into<String>) {
+        self.send(UiMessage::Focus(Some(id.into())));
+    }
+
+    pub fn blur(&self) {
+        self.send(UiMessage::Focus(None));
+    }
+
+    pub fn show(&self, id: impl Into<String>) {
+        self.set_displayed(id, Some(true));
+    }
+
+    pub fn hide(&self, id: impl Into<String>) {
+        self.set_displayed(id, Some(false));
+    }
+
+    pub fn toggle(&self, id: impl Into<String>) {
+        self.set_displayed(id, None);
+    }
+
+    pub fn add_class(&self, id: impl Into<String>, class: impl Into<String>) {
+        self.set_class(id, class, Some(true));
+    }
+
+    pub fn remove_class(&self, id: impl Into<String>, class: impl Into<String>) {
+        self.set_class(id, class, Some(false));
+    }
+
+    pub fn toggle_class(&self, id: impl Into<String>, class: impl Into<String>) {
+        self.set_class(id, class, None);
+    }
+
+    /// Remove a widget or container.
+    pub fn remove(&self, id: impl Into<String>) {
+        self.send(UiMessage::RemoveWidget(id.into()));
+    }
+
+    /// Run a keymap action as if its keys were pressed.
+    pub fn trigger_action(&self, action: impl Into<String>) {
+        self.send(UiMessage::TriggerAction(action.into()));
+    }
+
+    /// Dispatch a custom event from a node, bubbling to the root.
+    pub fn dispatch(&self, target_id: impl Into<String>, event: CustomEvent) {
+        self.send(UiMessage::Dispatch {
+            target_id: target_id.into(),
+            event,
+        });
+    }
+
+    /// Run `callback` once after `delay`.
+    pub fn set_timeout<F>(&self, delay: Duration, callback: F) -> TimerId
+    where
+        F: FnOnce(&TimerContext) + Send + 'static,
+    {
+        self.add_timer(TimerKind::Timeout(delay), timer::once(callback))
+    }
+
+    /// Run `callback` every `period` until the timer is cleared. Periods
+    /// shorter than a frame (16ms) are raised to one frame.
+    pub fn set_interval<F>(&self, period: Duration, callback: F) -> TimerId
+    where
+        F: FnMut(&TimerContext) + Send + 'static,
+    {
+        self.add_timer(TimerKind::Interval(period), Box::new(callback))
+    }
+
+    /// Run `callback` once before the next frame is laid out.
+    pub fn request_animation_frame<F>(&self, callback: F) -> TimerId
+    where
+        F: FnOnce(&TimerContext) + Send + 'static,
+    {
+        self.add_timer(TimerKind::AnimationFrame, timer::once(callback))
+    }
+
+    /// Animate a node's style; cancel with `clear_timer`.
+    pub fn animate(&self, id: impl Into<String>, animation: Animation) -> TimerId {
+        let timer_id = TimerId::new();
+        self.send(UiMessage::Animate {
+            id: id.into(),
+            timer_id,
+            animation,
+        });
+        timer_id
+    }
+
+    /// Cancel a timeout, interval, animation frame request or animation.
+    pub fn clear_timer(&self, timer_id: TimerId) {
+        self.send(UiMessage::ClearTimer(timer_id));
+    }
+
+    fn add_timer(&self, kind: TimerKind, callback: timer::TimerCallback) -> TimerId {
+        let timer_id = TimerId::new();
+        self.send(UiMessage::AddTimer {
+            timer_id,
+            kind,
+            callback,
+        });
+        timer_id
+    }
+
+    fn set_displayed(&self, id: impl Into<String>, shown: Option<bool>) {
+        self.send(UiMessage::SetDisplayed {
+            id: id.into(),
+            shown,
+        });
+    }
+
+    fn set_class(&self, id: impl Into<String>, class: impl Into<String>, enable: Option<bool>) {
+        self.send(UiMessage::SetClass {
+            id: id.into(),
+            class: class.into(),
+            enable,
+        });
+    }
+
+    /// Take everything queued so far.
+    pub(crate) fn take(&self) -> Vec<UiMessage> {
+        std::mem::take(&mut *self.queue.lock())
+    }
+
+    /// Wait until something is queued (returns at once if something was
+    /// queued since the last wait).
+    pub(crate) async fn queued(&self) {
+        self.queued.notified().await;
+    }
+}
+
+/// Set the value of whichever text-entry widget receives it.
+struct SetValue(String);
+
+impl WidgetMessage for SetValue {
+    fn apply(self: Box<Self>, widget: &mut dyn Widget) {
+        // Each message ignores widgets of other types
+        Box::new(InputMessage::SetValue(self.0.clone())).apply(widget);
+        Box::new(TextareaMessage::SetValue(self.0)).apply(widget);
+    }
+}
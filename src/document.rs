@@ -1,16 +1,29 @@
 use crossterm::{
     ExecutableCommand,
-    event::DisableMouseCapture,
+    event::{
+        DisableBracketedPaste, DisableFocusChange, DisableMouseCapture, KeyboardEnhancementFlags,
+        PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+    },
     terminal::{self, EnterAlternateScreen, LeaveAlternateScreen},
 };
+use parking_lot::Mutex;
 use ratatui::{Terminal, backend::CrosstermBackend};
 use tokio::sync::mpsc;
 
-use crate::event::{Event, EventContext, EventType, ListenerId, UiMessage};
+use crate::animation::{Animation, Easing};
+use crate::event::{
+    CustomEvent, Event, EventContext, EventType, ListenerGuard, ListenerId, UiMessage,
+};
 use crate::internal::RenderLoop;
+use crate::key::KeySequence;
+use crate::keymap::Keymap;
 use crate::style::Style;
+use crate::stylesheet::Stylesheet;
+use crate::theme::Theme;
+use crate::timer::{self, TimerContext, TimerId, TimerKind};
 use crate::widget::Widget;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 /// A container that can hold widgets and other containers.
 pub trait Container {
@@ -30,22 +43,99 @@ pub trait Container {
 /// Base trait for all widget handles.
 pub trait WidgetHandle: Clone + Send + 'static {
     fn id(&self) -> &str;
+    /// Style the widget was created with. Changes made through the handle
+    /// aren't reflected here.
     fn style(&self) -> &Style;
     fn ui_tx(&self) -> &mpsc::Sender<UiMessage>;
 
     /// Update the widget's style.
+    ///
+    /// `f` runs in the render loop on the widget's current inline style, so
+    /// properties it doesn't touch keep their values (e.g. from `hide`,
+    /// `set_disabled` or a running animation).
     fn update_style<F>(&self, f: F) -> Result<(), mpsc::error::TrySendError<UiMessage>>
     where
-        F: FnOnce(&mut Style),
+        F: FnOnce(&mut Style) + Send + 'static,
     {
-        let mut new_style = self.style().clone();
-        f(&mut new_style);
+        send_style_update(self.ui_tx(), self.id(), f)
+    }
+
+    /// Animate the widget's style towards the result of `f`.
+    ///
+    /// Returns an id that `Document::clear_timer` cancels with.
+    fn animate<F>(
+        &self,
+        f: F,
+        duration: std::time::Duration,
+        easing: Easing,
+    ) -> Result<TimerId, mpsc::error::TrySendError<UiMessage>>
+    where
+        F: FnOnce(&mut Style) + Send + 'static,
+    {
+        self.animate_with(Animation::new(f, duration).easing(easing))
+    }
+
+    /// Run an animation, e.g. one with an `on_complete` callback.
+    fn animate_with(
+        &self,
+        animation: Animation,
+    ) -> Result<TimerId, mpsc::error::TrySendError<UiMessage>> {
+        send_animation(self.ui_tx(), self.id(), animation)
+    }
+
+    /// Add a stylesheet class to the widget.
+    fn add_class(
+        &self,
+        class: impl Into<String>,
+    ) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        send_class(self.ui_tx(), self.id(), class.into(), Some(true))
+    }
+
+    /// Remove a stylesheet class from the widget.
+    fn remove_class(
+        &self,
+        class: impl Into<String>,
+    ) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        send_class(self.ui_tx(), self.id(), class.into(), Some(false))
+    }
+
+    /// Toggle a stylesheet class on the widget.
+    fn toggle_class(
+        &self,
+        class: impl Into<String>,
+    ) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        send_class(self.ui_tx(), self.id(), class.into(), None)
+    }
+
+    /// Show the widget (`display: flex`).
+    fn show(&self) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        send_displayed(self.ui_tx(), self.id(), Some(true))
+    }
 
-        self.ui_tx().try_send(UiMessage::UpdateStyle {
+    /// Hide the widget (`display: none`), keeping its state and listeners.
+    fn hide(&self) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        send_displayed(self.ui_tx(), self.id(), Some(false))
+    }
+
+    /// Toggle between shown and hidden.
+    fn toggle(&self) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        send_displayed(self.ui_tx(), self.id(), None)
+    }
+
+    /// Disable or re-enable the widget. Disabled widgets lose focus and
+    /// ignore keys and clicks.
+    fn set_disabled(&self, disabled: bool) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.ui_tx().try_send(UiMessage::SetDisabled {
             id: self.id().into(),
-            style: new_style.clone(),
+            disabled,
         })?;
+        Ok(())
+    }
 
+    /// Give the widget keyboard focus.
+    fn focus(&self) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.ui_tx()
+            .try_send(UiMessage::Focus(Some(self.id().into())))?;
         Ok(())
     }
 
@@ -57,6 +147,61 @@ pub trait WidgetHandle: Clone + Send + 'static {
     }
 }
 
+fn send_class(
+    ui_tx: &mpsc::Sender<UiMessage>,
+    id: &str,
+    class: String,
+    enable: Option<bool>,
+) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+    ui_tx.try_send(UiMessage::SetClass {
+        id: id.into(),
+        class,
+        enable,
+    })?;
+    Ok(())
+}
+
+fn send_animation(
+    ui_tx: &mpsc::Sender<UiMessage>,
+    id: &str,
+    animation: Animation,
+) -> Result<TimerId, mpsc::error::TrySendError<UiMessage>> {
+    let timer_id = TimerId::new();
+    ui_tx.try_send(UiMessage::Animate {
+        id: id.into(),
+        timer_id,
+        animation,
+    })?;
+    Ok(timer_id)
+}
+
+fn send_style_update<F>(
+    ui_tx: &mpsc::Sender<UiMessage>,
+    id: &str,
+    f: F,
+) -> Result<(), mpsc::error::TrySendError<UiMessage>>
+where
+    F: FnOnce(&mut Style) + Send + 'static,
+{
+    ui_tx.try_send(UiMessage::UpdateStyle {
+        id: id.into(),
+        update: Box::new(f),
+    })?;
+    Ok(())
+}
+
+fn send_displayed(
+    ui_tx: &mpsc::Sender<UiMessage>,
+    id: &str,
+    shown: Option<bool>,
+) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+    ui_tx.try_send(UiMessage::SetDisplayed {
+        id: id.into(),
+        shown,
+    })?;
+    Ok(())
+}
+
 /// Handle to the UI system.
 ///
 /// `Document` serves as the event bus for the UI.
@@ -65,14 +210,22 @@ pub struct Document {
     containers: HashMap<String, ContainerHandle>,
     ui_tx: mpsc::Sender<UiMessage>,
     event_rx: mpsc::Receiver<Event>,
+    focused_id: Arc<Mutex<Option<String>>>,
+    /// Whether keyboard enhancement flags were pushed and need popping.
+    keyboard_enhanced: bool,
 }
 
 impl Drop for Document {
     fn drop(&mut self) {
         // Cleanup terminal on exit
+        if self.keyboard_enhanced {
+            let _ = std::io::stdout().execute(PopKeyboardEnhancementFlags);
+        }
         let _ = terminal::disable_raw_mode();
         let _ = std::io::stdout().execute(LeaveAlternateScreen);
         let _ = std::io::stdout().execute(DisableMouseCapture);
+        let _ = std::io::stdout().execute(DisableBracketedPaste);
+        let _ = std::io::stdout().execute(DisableFocusChange);
     }
 }
 
@@ -86,7 +239,7 @@ impl Container for Document {
         self.ui_tx.try_send(UiMessage::AddContainer {
             parent_id: "root".to_string(),
             id: id.clone(),
-            style: style.clone(),
+            style: Box::new(style.clone()),
         })?;
         let handle = ContainerHandle {
             style: style.clone(),
@@ -104,15 +257,14 @@ impl Container for Document {
     ) -> Result<C::Handle, mpsc::error::TrySendError<UiMessage>> {
         let id = id.into();
         let style = widget.node_style_hint().unwrap_or_default();
+        let handle = widget.create_handle(id.clone(), self.ui_tx.clone(), style.clone());
         self.ui_tx.try_send(UiMessage::AddWidget {
             parent_id: "root".to_string(),
             id: id.clone(),
+            kind: C::kind(),
             widget: Box::new(widget),
-            style: style.clone(),
+            style: Box::new(style.clone()),
         })?;
-
-        // Create and return the specific Handle type
-        let handle = C::create_handle(id, self.ui_tx.clone(), style);
         Ok(handle)
     }
 }
@@ -145,7 +297,9 @@ impl Document {
     /// Add an event listener to an element.
     ///
     /// The listener will be called when the specified event occurs on the target element.
-    /// Supported events: Click, ScrollUp, ScrollDown, Hover
+    /// Supported events: Click, DoubleClick, TripleClick, RightClick, MiddleClick,
+    /// ScrollUp, ScrollDown, Hover, MouseDown, MouseUp,
+    /// MouseEnter, MouseLeave, DragStart, Drag, DragEnd, Change, Submit, Press
     pub fn add_event_listener<F>(
         &self,
         target_id: impl Into<String>,
@@ -161,11 +315,185 @@ impl Document {
             event_type,
             listener: std::sync::Arc::new(listener),
             listener_id,
+            capture: false,
+        })?;
+        Ok(listener_id)
+    }
+
+    /// Add an async event listener to an element.
+    ///
+    /// Each event spawns the returned future as a tokio task, so the listener
+    /// can await IO without blocking the UI. The task runs after dispatch has
+    /// finished, so `stop_propagation` and `prevent_default` have no effect;
+    /// use a sync listener for those. `ctx.ui()` changes wake the render loop
+    /// and apply on the next frame, also after an `.await`.
+    pub fn add_async_listener<F, Fut>(
+        &self,
+        target_id: impl Into<String>,
+        event_type: EventType,
+        listener: F,
+    ) -> Result<ListenerId, mpsc::error::TrySendError<UiMessage>>
+    where
+        F: Fn(EventContext) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = ()> + Send + 'static,
+    {
+        self.add_event_listener(target_id, event_type, move |ctx| {
+            tokio::spawn(listener(ctx));
+        })
+    }
+
+    /// Dispatch a custom event from an element.
+    ///
+    /// It goes through capture and bubbling like built-in events, then global
+    /// listeners. Listen for it with `EventType::Custom(name)`.
+    pub fn dispatch(
+        &self,
+        target_id: impl Into<String>,
+        event: CustomEvent,
+    ) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.ui_tx.try_send(UiMessage::Dispatch {
+            target_id: target_id.into(),
+            event,
+        })?;
+        Ok(())
+    }
+
+    /// Add an event listener that runs only for the first matching event,
+    /// then removes itself.
+    pub fn add_once_listener<F>(
+        &self,
+        target_id: impl Into<String>,
+        event_type: EventType,
+        listener: F,
+    ) -> Result<ListenerId, mpsc::error::TrySendError<UiMessage>>
+    where
+        F: FnOnce(EventContext) + Send + 'static,
+    {
+        let listener_id = ListenerId::new();
+        let listener = Mutex::new(Some(listener));
+        self.ui_tx.try_send(UiMessage::AddEventListener {
+            target_id: target_id.into(),
+            event_type,
+            listener: std::sync::Arc::new(move |ctx: EventContext| {
+                if let Some(listener) = listener.lock().take() {
+                    ctx.ui()
+                        .send(UiMessage::RemoveEventListener { listener_id });
+                    listener(ctx);
+                }
+            }),
+            listener_id,
+            capture: false,
+        })?;
+        Ok(listener_id)
+    }
+
+    /// Run `callback` once after `delay`, inside the render loop.
+    pub fn set_timeout<F>(
+        &self,
+        delay: std::time::Duration,
+        callback: F,
+    ) -> Result<TimerId, mpsc::error::TrySendError<UiMessage>>
+    where
+        F: FnOnce(&TimerContext) + Send + 'static,
+    {
+        self.add_timer(TimerKind::Timeout(delay), timer::once(callback))
+    }
+
+    /// Run `callback` every `period`, inside the render loop, until cleared.
+    /// Periods shorter than a frame (16ms) are raised to one frame.
+    pub fn set_interval<F>(
+        &self,
+        period: std::time::Duration,
+        callback: F,
+    ) -> Result<TimerId, mpsc::error::TrySendError<UiMessage>>
+    where
+        F: FnMut(&TimerContext) + Send + 'static,
+    {
+        self.add_timer(TimerKind::Interval(period), Box::new(callback))
+    }
+
+    /// Run `callback` once before the next frame is laid out.
+    ///
+    /// Frames are paced to about 60 per second; request another frame from
+    /// the callback (`ctx.ui().request_animation_frame`) to keep animating.
+    pub fn request_animation_frame<F>(
+        &self,
+        callback: F,
+    ) -> Result<TimerId, mpsc::error::TrySendError<UiMessage>>
+    where
+        F: FnOnce(&TimerContext) + Send + 'static,
+    {
+        self.add_timer(TimerKind::AnimationFrame, timer::once(callback))
+    }
+
+    /// Cancel a timeout, interval, animation frame request or animation.
+    pub fn clear_timer(
+        &self,
+        timer_id: TimerId,
+    ) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.ui_tx.try_send(UiMessage::ClearTimer(timer_id))?;
+        Ok(())
+    }
+
+    fn add_timer(
+        &self,
+        kind: TimerKind,
+        callback: timer::TimerCallback,
+    ) -> Result<TimerId, mpsc::error::TrySendError<UiMessage>> {
+        let timer_id = TimerId::new();
+        self.ui_tx.try_send(UiMessage::AddTimer {
+            timer_id,
+            kind,
+            callback,
+        })?;
+        Ok(timer_id)
+    }
+
+    /// Tie a listener's lifetime to a guard: it's removed when the guard is dropped.
+    ///
+    /// ```no_run
+    /// # use ccui::EventType;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let doc = ccui::Ui::run()?;
+    /// let guard = doc.guard(doc.add_global_listener(EventType::Resize, |_| {})?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn guard(&self, listener_id: ListenerId) -> ListenerGuard {
+        ListenerGuard::new(listener_id, self.ui_tx.clone())
+    }
+
+    /// Add a capture-phase event listener to an element.
+    ///
+    /// Capture listeners run on the way down from the root to the target,
+    /// before any bubbling listener and before the focused widget handles a key.
+    /// Use `ctx.prevent_default()` to stop the default handling, e.g. to filter
+    /// the characters an `Input` accepts, or listen on `"root"` for shortcuts
+    /// that beat the focused widget.
+    pub fn add_capture_listener<F>(
+        &self,
+        target_id: impl Into<String>,
+        event_type: EventType,
+        listener: F,
+    ) -> Result<ListenerId, mpsc::error::TrySendError<UiMessage>>
+    where
+        F: Fn(EventContext) + Send + Sync + 'static,
+    {
+        let listener_id = ListenerId::new();
+        self.ui_tx.try_send(UiMessage::AddEventListener {
+            target_id: target_id.into(),
+            event_type,
+            listener: std::sync::Arc::new(listener),
+            listener_id,
+            capture: true,
         })?;
         Ok(listener_id)
     }
 
     /// Remove an event listener by its ID.
+    ///
+    /// Works for element, global, key binding and action listeners. Element
+    /// listeners are also dropped when their element is removed.
     pub fn remove_event_listener(
         &self,
         listener_id: ListenerId,
@@ -196,6 +524,196 @@ impl Document {
         Ok(listener_id)
     }
 
+    /// Add a global event listener that runs only for the first matching
+    /// event, then removes itself.
+    pub fn add_once_global_listener<F>(
+        &self,
+        event_type: EventType,
+        listener: F,
+    ) -> Result<ListenerId, mpsc::error::TrySendError<UiMessage>>
+    where
+        F: FnOnce(EventContext) + Send + 'static,
+    {
+        let listener_id = ListenerId::new();
+        let listener = Mutex::new(Some(listener));
+        self.ui_tx.try_send(UiMessage::AddGlobalListener {
+            event_type,
+            listener: std::sync::Arc::new(move |ctx: EventContext| {
+                if let Some(listener) = listener.lock().take() {
+                    ctx.ui()
+                        .send(UiMessage::RemoveEventListener { listener_id });
+                    listener(ctx);
+                }
+            }),
+            listener_id,
+        })?;
+        Ok(listener_id)
+    }
+
+    /// Bind a key or a key sequence, e.g. `KeySequence::parse("ctrl+x ctrl+s")?`.
+    ///
+    /// Bindings are checked before the focused widget sees the key. While a
+    /// sequence is being typed its keys are held back; if the sequence breaks
+    /// off or the next key doesn't arrive within the chord timeout, the longest
+    /// bound part fires and the remaining keys are delivered as ordinary key
+    /// presses. Plain characters typed into an `Input` or `Textarea` are left
+    /// to it. Remove with `remove_event_listener`.
+    pub fn bind<F>(
+        &self,
+        keys: impl Into<KeySequence>,
+        listener: F,
+    ) -> Result<ListenerId, mpsc::error::TrySendError<UiMessage>>
+    where
+        F: Fn(EventContext) + Send + Sync + 'static,
+    {
+        let listener_id = ListenerId::new();
+        self.ui_tx.try_send(UiMessage::AddKeyBinding {
+            keys: keys.into(),
+            listener: std::sync::Arc::new(listener),
+            listener_id,
+        })?;
+        Ok(listener_id)
+    }
+
+    /// Set how long `bind` waits for the next key of a sequence (default 1s).
+    pub fn set_chord_timeout(
+        &self,
+        timeout: std::time::Duration,
+    ) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.ui_tx.try_send(UiMessage::SetChordTimeout(timeout))?;
+        Ok(())
+    }
+
+    /// Set how close in time and space (in cells) clicks must be to count as a
+    /// double or triple click (default 500ms, 1 cell).
+    pub fn set_multi_click(
+        &self,
+        interval: std::time::Duration,
+        distance: u16,
+    ) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.ui_tx
+            .try_send(UiMessage::SetMultiClick { interval, distance })?;
+        Ok(())
+    }
+
+    /// Replace the active keymap.
+    ///
+    /// Keymap bindings are checked before the focused widget sees the key.
+    pub fn set_keymap(&self, keymap: Keymap) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.ui_tx.try_send(UiMessage::SetKeymap(keymap))?;
+        Ok(())
+    }
+
+    /// Handle a named action, whichever element is focused.
+    ///
+    /// Runs after `EventType::Action` listeners on the focused element and its ancestors.
+    pub fn on_action<F>(
+        &self,
+        action: impl Into<String>,
+        listener: F,
+    ) -> Result<ListenerId, mpsc::error::TrySendError<UiMessage>>
+    where
+        F: Fn(EventContext) + Send + Sync + 'static,
+    {
+        let listener_id = ListenerId::new();
+        self.ui_tx.try_send(UiMessage::AddActionListener {
+            action: action.into(),
+            listener: std::sync::Arc::new(listener),
+            listener_id,
+        })?;
+        Ok(listener_id)
+    }
+
+    /// Run an action as if its keys were pressed (e.g. from a menu).
+    pub fn trigger_action(
+        &self,
+        action: impl Into<String>,
+    ) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.ui_tx
+            .try_send(UiMessage::TriggerAction(action.into()))?;
+        Ok(())
+    }
+
+    /// Replace the active stylesheet.
+    ///
+    /// Rules override each node's defaults but not its inline style; see
+    /// [`crate::stylesheet`] for the full cascade.
+    pub fn set_stylesheet(
+        &self,
+        stylesheet: Stylesheet,
+    ) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.ui_tx.try_send(UiMessage::SetStylesheet(stylesheet))?;
+        Ok(())
+    }
+
+    /// Add a stylesheet class to an element.
+    pub fn add_class(
+        &self,
+        id: impl Into<String>,
+        class: impl Into<String>,
+    ) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        send_class(&self.ui_tx, &id.into(), class.into(), Some(true))
+    }
+
+    /// Remove a stylesheet class from an element.
+    pub fn remove_class(
+        &self,
+        id: impl Into<String>,
+        class: impl Into<String>,
+    ) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        send_class(&self.ui_tx, &id.into(), class.into(), Some(false))
+    }
+
+    /// Toggle a stylesheet class on an element.
+    pub fn toggle_class(
+        &self,
+        id: impl Into<String>,
+        class: impl Into<String>,
+    ) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        send_class(&self.ui_tx, &id.into(), class.into(), None)
+    }
+
+    /// Set the active theme.
+    ///
+    /// Style colors that reference a `ThemeColor` are resolved against this theme
+    /// at draw time, so switching themes re-renders without updating individual nodes.
+    pub fn set_theme(&self, theme: Theme) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.ui_tx.try_send(UiMessage::SetTheme(theme))?;
+        Ok(())
+    }
+
+    /// Move keyboard focus to an element.
+    ///
+    /// Hidden or disabled elements are ignored.
+    pub fn focus(&self, id: impl Into<String>) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.ui_tx.try_send(UiMessage::Focus(Some(id.into())))?;
+        Ok(())
+    }
+
+    /// Clear keyboard focus.
+    pub fn blur(&self) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.ui_tx.try_send(UiMessage::Focus(None))?;
+        Ok(())
+    }
+
+    /// Id of the currently focused element, as of the last processed frame.
+    pub fn focused_id(&self) -> Option<String> {
+        self.focused_id.lock().clone()
+    }
+
+    /// Enable or disable arrow-key focus navigation.
+    ///
+    /// When enabled, arrow keys the focused widget doesn't handle move focus
+    /// to the nearest focusable element in that direction.
+    pub fn set_spatial_navigation(
+        &self,
+        enabled: bool,
+    ) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.ui_tx
+            .try_send(UiMessage::SetSpatialNavigation(enabled))?;
+        Ok(())
+    }
+
     /// Toggle mouse capture on/off.
     ///
     /// When mouse capture is off, the terminal handles mouse events natively
@@ -213,6 +731,8 @@ impl Document {
 /// Handle to a container.
 #[derive(Clone)]
 pub struct ContainerHandle {
+    /// Style the container was created with. Changes made through the handle
+    /// aren't reflected here.
     pub style: Style,
     ui_tx: mpsc::Sender<UiMessage>,
     id: String,
@@ -224,17 +744,97 @@ impl ContainerHandle {
     }
 
     /// Update style using a closure.
-    pub fn update_style<F>(&mut self, f: F) -> Result<(), mpsc::error::TrySendError<UiMessage>>
+    ///
+    /// `f` runs in the render loop on the container's current inline style,
+    /// so properties it doesn't touch keep their values.
+    pub fn update_style<F>(&self, f: F) -> Result<(), mpsc::error::TrySendError<UiMessage>>
     where
-        F: FnOnce(&mut Style),
+        F: FnOnce(&mut Style) + Send + 'static,
     {
-        f(&mut self.style);
-        self.ui_tx.try_send(UiMessage::UpdateStyle {
-            id: self.id.clone(),
-            style: self.style.clone(),
-        })?;
+        send_style_update(&self.ui_tx, &self.id, f)
+    }
+
+    /// Show the container (`display: flex`).
+    pub fn show(&self) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        send_displayed(&self.ui_tx, &self.id, Some(true))
+    }
+
+    /// Hide the container (`display: none`), keeping its children and listeners.
+    pub fn hide(&self) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        send_displayed(&self.ui_tx, &self.id, Some(false))
+    }
+
+    /// Toggle between shown and hidden.
+    pub fn toggle(&self) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        send_displayed(&self.ui_tx, &self.id, None)
+    }
+
+    /// Make the container modal: Tab and mouse input stay inside it while it is shown.
+    ///
+    /// Focus moves to its first focusable element, and returns to the previously
+    /// focused element when the container is removed, hidden or released.
+    pub fn trap_focus(&self) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.update_style(|style| style.modal = true)
+    }
+
+    /// Stop trapping focus inside the container.
+    pub fn release_focus(&self) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.update_style(|style| style.modal = false)
+    }
+
+    /// Animate the container's style towards the result of `f`.
+    ///
+    /// Returns an id that `Document::clear_timer` cancels with.
+    pub fn animate<F>(
+        &self,
+        f: F,
+        duration: std::time::Duration,
+        easing: Easing,
+    ) -> Result<TimerId, mpsc::error::TrySendError<UiMessage>>
+    where
+        F: FnOnce(&mut Style) + Send + 'static,
+    {
+        self.animate_with(Animation::new(f, duration).easing(easing))
+    }
+
+    /// Run an animation, e.g. one with an `on_complete` callback.
+    pub fn animate_with(
+        &self,
+        animation: Animation,
+    ) -> Result<TimerId, mpsc::error::TrySendError<UiMessage>> {
+        send_animation(&self.ui_tx, &self.id, animation)
+    }
+
+    /// Give the container keyboard focus.
+    pub fn focus(&self) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.ui_tx
+            .try_send(UiMessage::Focus(Some(self.id.clone())))?;
         Ok(())
     }
+
+    /// Add a stylesheet class to the container.
+    pub fn add_class(
+        &self,
+        class: impl Into<String>,
+    ) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        send_class(&self.ui_tx, &self.id, class.into(), Some(true))
+    }
+
+    /// Remove a stylesheet class from the container.
+    pub fn remove_class(
+        &self,
+        class: impl Into<String>,
+    ) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        send_class(&self.ui_tx, &self.id, class.into(), Some(false))
+    }
+
+    /// Toggle a stylesheet class on the container.
+    pub fn toggle_class(
+        &self,
+        class: impl Into<String>,
+    ) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        send_class(&self.ui_tx, &self.id, class.into(), None)
+    }
 }
 
 impl Container for ContainerHandle {
@@ -247,7 +847,7 @@ impl Container for ContainerHandle {
         self.ui_tx.try_send(UiMessage::AddContainer {
             parent_id: self.id.clone(),
             id: id.clone(),
-            style: style.clone(),
+            style: Box::new(style.clone()),
         })?;
         Ok(ContainerHandle {
             style,
@@ -263,15 +863,14 @@ impl Container for ContainerHandle {
     ) -> Result<C::Handle, mpsc::error::TrySendError<UiMessage>> {
         let id = id.into();
         let style = widget.node_style_hint().unwrap_or_default();
+        let handle = widget.create_handle(id.clone(), self.ui_tx.clone(), style.clone());
         self.ui_tx.try_send(UiMessage::AddWidget {
             parent_id: self.id.clone(),
             id: id.clone(),
+            kind: C::kind(),
             widget: Box::new(widget),
-            style: style.clone(),
+            style: Box::new(style.clone()),
         })?;
-
-        // Create and return the specific Handle type
-        let handle = C::create_handle(id, self.ui_tx.clone(), style);
         Ok(handle)
     }
 }
@@ -291,14 +890,26 @@ impl Ui {
         terminal::enable_raw_mode()?;
         std::io::stdout().execute(EnterAlternateScreen)?;
         std::io::stdout().execute(crossterm::event::EnableMouseCapture)?;
+        // Deliver pastes as one event instead of a key per character
+        std::io::stdout().execute(crossterm::event::EnableBracketedPaste)?;
+        std::io::stdout().execute(crossterm::event::EnableFocusChange)?;
+        // Report modified keys like Ctrl+Enter where the terminal supports it
+        let keyboard_enhanced = terminal::supports_keyboard_enhancement().unwrap_or(false);
+        if keyboard_enhanced {
+            std::io::stdout().execute(PushKeyboardEnhancementFlags(
+                KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES,
+            ))?;
+        }
 
         let terminal = Terminal::new(CrosstermBackend::new(std::io::stdout()))?;
 
         let (ui_tx, ui_rx) = mpsc::channel(100);
         let (event_tx, event_rx) = mpsc::channel(100);
+        let focused_id = Arc::new(Mutex::new(None));
 
+        let focus_mirror = focused_id.clone();
         tokio::spawn(async move {
-            if let Err(e) = RenderLoop::run(terminal, ui_rx, event_tx).await {
+            if let Err(e) = RenderLoop::run(terminal, ui_rx, event_tx, focus_mirror).await {
                 eprintln!("Render error: {}", e);
             }
         });
@@ -318,6 +929,8 @@ impl Ui {
             containers,
             ui_tx,
             event_rx,
+            focused_id,
+            keyboard_enhanced,
         })
     }
 }
@@ -1,6 +1,13 @@
-use crossterm::event::{KeyCode, KeyEvent};
+use crate::commands::UiCommands;
+use crate::key::{KeyBinding, KeySequence};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton};
+use std::any::Any;
+use std::fmt;
+use std::pin::Pin;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::task::{Context, Poll};
+use tokio::sync::mpsc;
 
 /// Unique identifier for an event listener.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -13,6 +20,12 @@ impl ListenerId {
     }
 }
 
+impl Default for ListenerId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Trait for widget-specific messages.
 /// Each widget defines its own message types and implements this trait.
 pub trait WidgetMessage: Send + 'static {
@@ -24,17 +37,151 @@ pub trait WidgetMessage: Send + 'static {
 /// Event types that can be listened to.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum EventType {
+    /// Left button pressed; `click_count` counts rapid repeated clicks.
     Click,
+    /// Second left click in quick succession (fired after its `Click`).
     DoubleClick,
+    /// Third left click in quick succession (fired after its `Click`).
+    TripleClick,
+    /// Right button pressed, e.g. to open a context menu.
     RightClick,
+    /// Middle button pressed.
+    MiddleClick,
     ScrollUp,
     ScrollDown,
     ScrollLeft,
     ScrollRight,
     Hover,
+    /// Any mouse button pressed over the node (see `EventContext::button`).
+    MouseDown,
+    /// Any mouse button released over the node.
+    MouseUp,
+    /// The cursor moved onto the node or one of its descendants. Doesn't bubble.
+    MouseEnter,
+    /// The cursor left the node and all of its descendants. Doesn't bubble.
+    MouseLeave,
+    /// First movement with a button held; fired on the node the press started on.
+    DragStart,
+    /// Movement with a button held; `drag_delta` is the movement since the last event.
+    Drag,
+    /// Button released after a drag; `drag_delta` is the total movement.
+    DragEnd,
     Focus,
     Blur,
-    KeyPress(KeyCode),
+    /// Key press with modifiers, e.g. `KeyPress("ctrl+s".parse()?)`
+    /// or `KeyPress(KeyCode::Esc.into())`.
+    KeyPress(KeyBinding),
+    /// Named action from the keymap, bubbled from the focused element.
+    Action(String),
+    /// Text pasted into the terminal, in `value`. Bubbles from the focused element;
+    /// prevent default to keep it from being inserted into the focused widget.
+    Paste,
+    /// The terminal was resized; the new size is in `size`. Fired on the root.
+    Resize,
+    /// The terminal window gained focus. Fired on the root.
+    TerminalFocus,
+    /// The terminal window lost focus. Fired on the root.
+    TerminalBlur,
+    /// A widget's value changed through user input; the new value is in `value`.
+    Change,
+    /// A widget's value was submitted (Enter in `Input`, Ctrl+Enter, Alt+Enter
+    /// or Ctrl+J in `Textarea`).
+    Submit,
+    /// A `Button` was activated by a click, Enter or Space.
+    Press,
+    /// User-defined event such as `Custom("row-selected".into())`, sent with
+    /// `Document::dispatch`; read its data with `ctx.payload::<T>()`.
+    Custom(String),
+}
+
+/// Data attached to a custom event.
+pub type Payload = Arc<dyn Any + Send + Sync>;
+
+/// A user-defined event, dispatched from a node and bubbled like built-in ones.
+///
+/// ```no_run
+/// # use ccui::{CustomEvent, EventType};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let doc = ccui::Ui::run()?;
+/// doc.dispatch("table", CustomEvent::new("row-selected").with_payload(3usize))?;
+///
+/// doc.add_event_listener("page", EventType::Custom("row-selected".into()), |ctx| {
+///     let row = ctx.payload::<usize>();
+/// })?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct CustomEvent {
+    pub name: String,
+    pub payload: Option<Payload>,
+}
+
+impl CustomEvent {
+    pub fn new(name: impl Into<String>) -> Self {
+        CustomEvent {
+            name: name.into(),
+            payload: None,
+        }
+    }
+
+    /// Attach data that listeners can downcast with `ctx.payload::<T>()`.
+    pub fn with_payload<T: Any + Send + Sync>(mut self, payload: T) -> Self {
+        self.payload = Some(Arc::new(payload));
+        self
+    }
+}
+
+impl fmt::Debug for CustomEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomEvent")
+            .field("name", &self.name)
+            .field("has_payload", &self.payload.is_some())
+            .finish()
+    }
+}
+
+/// An event raised by a widget while handling input.
+///
+/// Widgets queue these and the render loop dispatches them from the widget's
+/// node (with capture and bubbling) once the input has been handled.
+#[derive(Clone, Debug)]
+pub enum WidgetEvent {
+    Change(String),
+    Submit(String),
+    Press,
+    /// A domain event of the widget's own, e.g. "row-selected".
+    Custom(CustomEvent),
+}
+
+impl WidgetEvent {
+    pub fn event_type(&self) -> EventType {
+        match self {
+            WidgetEvent::Change(_) => EventType::Change,
+            WidgetEvent::Submit(_) => EventType::Submit,
+            WidgetEvent::Press => EventType::Press,
+            WidgetEvent::Custom(event) => EventType::Custom(event.name.clone()),
+        }
+    }
+}
+
+/// Which part of dispatch a listener is called in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum EventPhase {
+    /// Root -> target, for listeners added with `add_capture_listener`.
+    Capture,
+    /// On the target itself.
+    #[default]
+    Target,
+    /// Target -> root.
+    Bubble,
+}
+
+/// Dispatch flags shared by every copy of one event's context.
+#[derive(Debug, Default)]
+pub(crate) struct EventFlags {
+    propagation_stopped: AtomicBool,
+    default_prevented: AtomicBool,
 }
 
 /// Context passed to event listeners.
@@ -48,32 +195,252 @@ pub struct EventContext {
     pub mouse_x: Option<u16>,
     pub mouse_y: Option<u16>,
     pub scroll_delta: Option<i32>,
+    /// Mouse button for button and drag events
+    pub button: Option<MouseButton>,
+    /// Consecutive clicks of the same button (1 = single, 2 = double, 3 = triple)
+    pub click_count: u8,
+    /// Modifiers held during a mouse or key event
+    pub modifiers: KeyModifiers,
+    /// Drag movement in cells (see `EventType::Drag` and `DragEnd`)
+    pub drag_delta: Option<(i32, i32)>,
+    /// Position where the drag's button was pressed
+    pub drag_origin: Option<(u16, u16)>,
+    /// Terminal size (width, height) for Resize events
+    pub size: Option<(u16, u16)>,
+    /// Widget value for Change and Submit events, pasted text for Paste
+    pub value: Option<String>,
+    /// Data attached to a custom event, see `payload()`
+    pub payload: Option<Payload>,
     pub key_code: Option<KeyCode>,
-    /// Whether event propagation should stop
-    pub propagation_stopped: bool,
+    /// Full key event (code, modifiers, kind) for key presses
+    pub key_event: Option<KeyEvent>,
+    pub phase: EventPhase,
+    pub(crate) flags: Arc<EventFlags>,
+    pub(crate) ui: UiCommands,
 }
 
 impl EventContext {
-    pub fn stop_propagation(&mut self) {
-        self.propagation_stopped = true;
+    /// Context for an event on `target_id`, with no mouse or key data.
+    pub fn new(event_type: EventType, target_id: impl Into<String>) -> Self {
+        let target_id = target_id.into();
+        EventContext {
+            event_type,
+            current_target_id: target_id.clone(),
+            target_id,
+            mouse_x: None,
+            mouse_y: None,
+            scroll_delta: None,
+            button: None,
+            click_count: 0,
+            modifiers: KeyModifiers::NONE,
+            drag_delta: None,
+            drag_origin: None,
+            size: None,
+            value: None,
+            payload: None,
+            key_code: None,
+            key_event: None,
+            phase: EventPhase::default(),
+            flags: Arc::default(),
+            ui: UiCommands::default(),
+        }
+    }
+
+    /// Context for a key press on `target_id`.
+    pub fn key(key: KeyEvent, target_id: impl Into<String>) -> Self {
+        EventContext {
+            key_code: Some(key.code),
+            modifiers: key.modifiers,
+            key_event: Some(key),
+            ..Self::new(EventType::KeyPress(key.into()), target_id)
+        }
+    }
+
+    /// The custom event's payload, if it has one of type `T`.
+    pub fn payload<T: Any>(&self) -> Option<&T> {
+        self.payload.as_deref()?.downcast_ref()
+    }
+
+    /// Queue UI changes to apply before the next frame is drawn.
+    pub fn ui(&self) -> &UiCommands {
+        &self.ui
+    }
+
+    /// Stop the event from reaching further listeners in the tree.
+    pub fn stop_propagation(&self) {
+        self.flags
+            .propagation_stopped
+            .store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_propagation_stopped(&self) -> bool {
+        self.flags.propagation_stopped.load(Ordering::Relaxed)
+    }
+
+    /// Skip the default handling that follows dispatch, such as the focused
+    /// widget's `handle_key`, Tab navigation, click-to-focus or scrolling.
+    pub fn prevent_default(&self) {
+        self.flags.default_prevented.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_default_prevented(&self) -> bool {
+        self.flags.default_prevented.load(Ordering::Relaxed)
     }
 }
 
 /// Event listener callback type.
 pub type EventListener = Arc<dyn Fn(EventContext) + Send + Sync + 'static>;
 
+/// A stream of typed events from one widget, e.g. `InputHandle::events`.
+///
+/// Use it with `tokio_stream::StreamExt`:
+///
+/// ```no_run
+/// # use ccui::widget::{InputEvent, InputHandle};
+/// # use tokio_stream::StreamExt;
+/// #
+/// # async fn example(input: InputHandle) -> Result<(), Box<dyn std::error::Error>> {
+/// let mut events = input.events()?;
+/// while let Some(event) = events.next().await {
+///     if let InputEvent::Submitted(value) = event {
+///         println!("{}", value);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The stream's listeners are removed when it's dropped, so keep it around
+/// rather than calling `events()` again for every event.
+pub struct EventStream<T> {
+    rx: mpsc::UnboundedReceiver<T>,
+    _guards: Vec<ListenerGuard>,
+}
+
+impl<T: Send + 'static> EventStream<T> {
+    /// Listen for `event_types` on `target_id`, turning each context into a
+    /// stream item with `map` (returning `None` skips the event).
+    pub(crate) fn subscribe(
+        target_id: &str,
+        ui_tx: &mpsc::Sender<UiMessage>,
+        event_types: &[EventType],
+        map: fn(&EventContext) -> Option<T>,
+    ) -> Result<Self, mpsc::error::TrySendError<UiMessage>> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let mut guards = Vec::new();
+        for event_type in event_types {
+            let tx = tx.clone();
+            let listener_id = ListenerId::new();
+            ui_tx.try_send(UiMessage::AddEventListener {
+                target_id: target_id.to_string(),
+                event_type: event_type.clone(),
+                listener: Arc::new(move |ctx| {
+                    // Only the widget's own events, not ones bubbling from children
+                    if ctx.target_id == ctx.current_target_id
+                        && let Some(item) = map(&ctx)
+                    {
+                        let _ = tx.send(item);
+                    }
+                }),
+                listener_id,
+                capture: false,
+            })?;
+            guards.push(ListenerGuard::new(listener_id, ui_tx.clone()));
+        }
+        Ok(EventStream {
+            rx,
+            _guards: guards,
+        })
+    }
+}
+
+impl<T> EventStream<T> {
+    /// Wait for the next event. Returns `None` once the widget has been
+    /// removed or the UI has shut down.
+    pub async fn recv(&mut self) -> Option<T> {
+        self.rx.recv().await
+    }
+}
+
+impl<T> tokio_stream::Stream for EventStream<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+/// Removes a listener when dropped.
+///
+/// Get one with `Document::guard`, and keep it alongside whatever the
+/// listener's closure captures so both go away together.
+#[must_use = "the listener is removed as soon as the guard is dropped"]
+pub struct ListenerGuard {
+    listener_id: ListenerId,
+    ui_tx: Option<mpsc::Sender<UiMessage>>,
+}
+
+impl ListenerGuard {
+    pub(crate) fn new(listener_id: ListenerId, ui_tx: mpsc::Sender<UiMessage>) -> Self {
+        ListenerGuard {
+            listener_id,
+            ui_tx: Some(ui_tx),
+        }
+    }
+
+    pub fn id(&self) -> ListenerId {
+        self.listener_id
+    }
+
+    /// Keep the listener registered after all; returns its id.
+    pub fn forget(mut self) -> ListenerId {
+        self.ui_tx = None;
+        self.listener_id
+    }
+}
+
+impl Drop for ListenerGuard {
+    fn drop(&mut self) {
+        let Some(ui_tx) = self.ui_tx.take() else {
+            return;
+        };
+        let message = UiMessage::RemoveEventListener {
+            listener_id: self.listener_id,
+        };
+        // The removal must not be lost while the queue is full: finish
+        // sending it from a task, or block when there is no runtime
+        if let Err(mpsc::error::TrySendError::Full(message)) = ui_tx.try_send(message) {
+            match tokio::runtime::Handle::try_current() {
+                Ok(runtime) => {
+                    runtime.spawn(async move {
+                        let _ = ui_tx.send(message).await;
+                    });
+                }
+                Err(_) => {
+                    let _ = ui_tx.blocking_send(message);
+                }
+            }
+        }
+    }
+}
+
+/// A change to a node's inline style, applied by the render loop to the
+/// node's current style.
+pub type StyleUpdate = Box<dyn FnOnce(&mut crate::style::Style) + Send + 'static>;
+
 /// Messages sent from external to the internal render loop.
 pub enum UiMessage {
     AddWidget {
         parent_id: String,
         id: String,
+        kind: crate::widget::WidgetKind,
         widget: Box<dyn crate::widget::Widget>,
-        style: crate::style::Style,
+        style: Box<crate::style::Style>,
     },
     AddContainer {
         parent_id: String,
         id: String,
-        style: crate::style::Style,
+        style: Box<crate::style::Style>,
     },
     RemoveWidget(String),
     UpdateWidget {
@@ -82,7 +449,24 @@ pub enum UiMessage {
     },
     UpdateStyle {
         id: String,
-        style: crate::style::Style,
+        update: StyleUpdate,
+    },
+    /// Add (`Some(true)`), remove (`Some(false)`) or toggle (`None`) a class.
+    SetClass {
+        id: String,
+        class: String,
+        enable: Option<bool>,
+    },
+    SetStylesheet(crate::stylesheet::Stylesheet),
+    /// Show (`Some(true)`), hide (`Some(false)`) or toggle (`None`) a node.
+    SetDisplayed {
+        id: String,
+        shown: Option<bool>,
+    },
+    /// Enable or disable a node; disabled nodes can't be focused or clicked.
+    SetDisabled {
+        id: String,
+        disabled: bool,
     },
 
     // Event system
@@ -91,6 +475,8 @@ pub enum UiMessage {
         event_type: EventType,
         listener: EventListener,
         listener_id: ListenerId,
+        /// Run in the capture phase (root -> target) instead of bubbling.
+        capture: bool,
     },
     RemoveEventListener {
         listener_id: ListenerId,
@@ -100,6 +486,43 @@ pub enum UiMessage {
         listener: EventListener,
         listener_id: ListenerId,
     },
+    /// Bind a key or multi-key sequence (chord) to a listener.
+    AddKeyBinding {
+        keys: KeySequence,
+        listener: EventListener,
+        listener_id: ListenerId,
+    },
+    SetChordTimeout(std::time::Duration),
+    /// Max time and distance (in cells) between clicks counted as a double click.
+    SetMultiClick {
+        interval: std::time::Duration,
+        distance: u16,
+    },
+    SetKeymap(crate::keymap::Keymap),
+    AddActionListener {
+        action: String,
+        listener: EventListener,
+        listener_id: ListenerId,
+    },
+    TriggerAction(String),
+    /// Start a timeout, interval or animation frame request.
+    AddTimer {
+        timer_id: crate::timer::TimerId,
+        kind: crate::timer::TimerKind,
+        callback: crate::timer::TimerCallback,
+    },
+    ClearTimer(crate::timer::TimerId),
+    /// Animate a node's inline style; cancel with `ClearTimer(timer_id)`.
+    Animate {
+        id: String,
+        timer_id: crate::timer::TimerId,
+        animation: crate::animation::Animation,
+    },
+    /// Dispatch a custom event from a node, bubbling to the root.
+    Dispatch {
+        target_id: String,
+        event: CustomEvent,
+    },
 
     // Widget-specific messages
     WidgetMessage {
@@ -107,6 +530,13 @@ pub enum UiMessage {
         message: Box<dyn WidgetMessage>,
     },
 
+    // Theme switch
+    SetTheme(crate::theme::Theme),
+
+    // Focus: move focus to a node (`Some`) or blur (`None`)
+    Focus(Option<String>),
+    SetSpatialNavigation(bool),
+
     // Mouse capture toggle
     ToggleMouseCapture,
 }
@@ -117,4 +547,10 @@ pub enum Event {
     Key(KeyEvent),
     Mouse(crossterm::event::MouseEvent),
     Resize(u16, u16),
+    /// Text pasted into the terminal (bracketed paste)
+    Paste(String),
+    /// The terminal window gained focus
+    FocusGained,
+    /// The terminal window lost focus
+    FocusLost,
 }
@@ -0,0 +1,130 @@
+use ratatui::layout::Rect;
+
+use super::Node;
+
+/// Direction for spatial (arrow key) focus navigation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// A focusable node: tab index, id, and doubled center point in screen space.
+type Focusable = (i32, String, (i32, i32));
+
+impl Node {
+    /// Collect focusable nodes in document order.
+    ///
+    /// `shift` maps this node's layout coordinates to screen space; it changes
+    /// inside ScrollViews, whose children are laid out in content coordinates.
+    /// Hidden subtrees, disabled nodes and negative tab indexes are skipped.
+    fn collect_focusable(&self, shift: (i32, i32), out: &mut Vec<Focusable>) {
+        if !self.style.is_shown() {
+            return;
+        }
+        if self.style.focusable && !self.style.disabled && self.style.tab_index >= 0 {
+            let (x, y) = center(self.area);
+            out.push((
+                self.style.tab_index,
+                self.id.clone(),
+                (x + 2 * shift.0, y + 2 * shift.1),
+            ));
+        }
+        // Same mapping as hit testing uses
+        let shift = if self.is_scroll_container() {
+            let (viewport, offset) = self.scroll_viewport();
+            (
+                shift.0 + viewport.x as i32 - offset.x as i32,
+                shift.1 + viewport.y as i32 - offset.y as i32,
+            )
+        } else {
+            shift
+        };
+        for child in &self.children {
+            child.collect_focusable(shift, out);
+        }
+    }
+
+    /// Focusable nodes in Tab order (positive tab indexes ascending, then 0 in
+    /// document order), with their doubled screen-space centers.
+    pub fn tab_order(&self) -> Vec<(String, (i32, i32))> {
+        let mut nodes = Vec::new();
+        self.collect_focusable((0, 0), &mut nodes);
+        // Stable sort keeps document order within the same tab index
+        nodes.sort_by_key(|(index, _, _)| if *index > 0 { *index } else { i32::MAX });
+        nodes
+            .into_iter()
+            .map(|(_, id, center)| (id, center))
+            .collect()
+    }
+
+    /// Id of the next (or previous) node in Tab order after `current`.
+    pub fn next_focus(&self, current: Option<&str>, forward: bool) -> Option<String> {
+        let order = self.tab_order();
+        if order.is_empty() {
+            return None;
+        }
+        let position = current.and_then(|id| order.iter().position(|(o, _)| o == id));
+        let next = match (position, forward) {
+            (Some(i), true) => (i + 1) % order.len(),
+            (Some(i), false) => (i + order.len() - 1) % order.len(),
+            (None, true) => 0,
+            (None, false) => order.len() - 1,
+        };
+        Some(order[next].0.clone())
+    }
+
+    /// Id of the nearest focusable node from `current` in the given direction.
+    pub fn spatial_focus(&self, current: &str, direction: FocusDirection) -> Option<String> {
+        let order = self.tab_order();
+        let (fx, fy) = order.iter().find(|(id, _)| id == current)?.1;
+
+        order
+            .iter()
+            .filter(|(id, _)| id != current)
+            .filter_map(|(id, (x, y))| {
+                let (dx, dy) = (x - fx, y - fy);
+                let (main, cross) = match direction {
+                    FocusDirection::Up => (-dy, dx),
+                    FocusDirection::Down => (dy, dx),
+                    FocusDirection::Left => (-dx, dy),
+                    FocusDirection::Right => (dx, dy),
+                };
+                // Only candidates in the requested direction; prefer aligned ones
+                (main > 0).then(|| (main + 2 * cross.abs(), id))
+            })
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, id)| id.clone())
+    }
+
+    /// Collect shown modal nodes as (z_index, id) in document order.
+    fn collect_modals(&self, out: &mut Vec<(i32, String)>) {
+        if !self.style.is_shown() {
+            return;
+        }
+        if self.style.modal {
+            out.push((self.style.z_index, self.id.clone()));
+        }
+        for child in &self.children {
+            child.collect_modals(out);
+        }
+    }
+
+    /// Ids of shown modal nodes, topmost last.
+    pub fn modal_ids(&self) -> Vec<String> {
+        let mut modals = Vec::new();
+        self.collect_modals(&mut modals);
+        modals.sort_by_key(|(z_index, _)| *z_index);
+        modals.into_iter().map(|(_, id)| id).collect()
+    }
+}
+
+/// Doubled center point (avoids rounding).
+fn center(area: Rect) -> (i32, i32) {
+    (
+        2 * area.x as i32 + area.width as i32,
+        2 * area.y as i32 + area.height as i32,
+    )
+}
@@ -0,0 +1,140 @@
+use ratatui::layout::{Position, Rect};
+
+use super::Node;
+use crate::layout::shrink_and_offset_border;
+use crate::style::{Overflow, PositionMode};
+
+impl Node {
+    /// Whether children are drawn through a ScrollView (in local coordinates).
+    pub fn is_scroll_container(&self) -> bool {
+        self.style.overflow != Overflow::Visible
+            && (self.widget.is_none() || !self.children.is_empty())
+    }
+
+    /// A scroll container's viewport (in its own layout coordinates) and
+    /// scroll offset. A child at `p` in content coordinates shows at
+    /// `p - offset + viewport.position`.
+    pub fn scroll_viewport(&self) -> (Rect, Position) {
+        let viewport = shrink_and_offset_border(&self.style, self.area);
+        let offset = self
+            .scroll_state
+            .as_ref()
+            .map(|state| state.offset())
+            .unwrap_or_default();
+        (viewport, offset)
+    }
+
+    /// Child indices in paint order (stable by z-index, so later siblings paint on top).
+    pub fn paint_order(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.children.len()).collect();
+        order.sort_by_key(|&i| self.children[i].style.z_index);
+        order
+    }
+
+    /// Whether `child` is lifted out of this node into the global floating layer.
+    ///
+    /// Floating nodes inside a ScrollView stay in place, since they are laid
+    /// out in its local coordinates.
+    fn lifts(&self, child: &Node, lift: bool) -> bool {
+        lift && !self.is_scroll_container() && child.style.position_mode == PositionMode::Floating
+    }
+
+    /// Floating subtrees painted above this subtree, bottom to top.
+    ///
+    /// Layers are ordered by z-index, then tree order. A nested floating node
+    /// never sinks below the layer it belongs to.
+    pub fn floating_layers(&self) -> Vec<String> {
+        let mut layers = Vec::new();
+        self.collect_layers(i32::MIN, true, &mut layers);
+        layers.sort_by_key(|(z_index, _)| *z_index);
+        layers.into_iter().map(|(_, id)| id).collect()
+    }
+
+    fn collect_layers(&self, base_z: i32, lift: bool, out: &mut Vec<(i32, String)>) {
+        if !self.style.is_shown() {
+            return;
+        }
+        let child_lift = lift && !self.is_scroll_container();
+        for i in self.paint_order() {
+            let child = &self.children[i];
+            if self.lifts(child, lift) {
+                let z_index = child.style.z_index.max(base_z);
+                out.push((z_index, child.id.clone()));
+                child.collect_layers(z_index, true, out);
+            } else {
+                child.collect_layers(base_z, child_lift, out);
+            }
+        }
+    }
+
+    /// Topmost, deepest node at a screen position, in the same order as painting,
+    /// with the point in that node's layout coordinates (those of its `area`).
+    fn hit(&self, x: u16, y: u16, lift: bool) -> Option<(String, Position)> {
+        let point = Position::new(x, y);
+        if !self.style.is_shown() || !self.area.contains(point) {
+            return None;
+        }
+
+        // Map the point into the ScrollView's content coordinates
+        let (x, y, child_lift) = if self.is_scroll_container() {
+            let (viewport, offset) = self.scroll_viewport();
+            if !viewport.contains(Position::new(x, y)) {
+                // On the border
+                return Some((self.id.clone(), point));
+            }
+            (x - viewport.x + offset.x, y - viewport.y + offset.y, false)
+        } else {
+            (x, y, lift)
+        };
+
+        for i in self.paint_order().into_iter().rev() {
+            let child = &self.children[i];
+            if self.lifts(child, lift) {
+                continue;
+            }
+            if let Some(hit) = child.hit(x, y, child_lift) {
+                return Some(hit);
+            }
+        }
+        Some((self.id.clone(), point))
+    }
+
+    /// Find the deepest node at the given position, and the position in the
+    /// coordinates of that node's `area` (they differ inside ScrollViews).
+    ///
+    /// Floating layers are tested first, topmost first, matching paint order.
+    pub fn hit_test(&self, x: u16, y: u16) -> Option<(String, Position)> {
+        if !self.style.is_shown() {
+            return None;
+        }
+        for id in self.floating_layers().iter().rev() {
+            if let Some(hit) = self.find_child(id).and_then(|layer| layer.hit(x, y, true)) {
+                return Some(hit);
+            }
+        }
+        self.hit(x, y, true)
+    }
+
+    /// Find the deepest node (widget or container) at the given position.
+    pub fn find_node_at(&self, x: u16, y: u16) -> Option<String> {
+        self.hit_test(x, y).map(|(id, _)| id)
+    }
+
+    /// Find the widget at the given position.
+    /// Returns the id of the topmost widget (or empty container) that contains the point.
+    pub fn find_widget_at(&self, x: u16, y: u16) -> Option<String> {
+        let id = self.find_node_at(x, y)?;
+        let node = self.find_child(&id)?;
+        (node.widget.is_some() || node.children.is_empty()).then_some(id)
+    }
+
+    /// Find a scrollview container at the given position.
+    /// Returns the nearest node with overflow != Visible around the topmost hit.
+    pub fn find_scrollview_at(&self, x: u16, y: u16) -> Option<String> {
+        let id = self.find_node_at(x, y)?;
+        self.ancestor_chain(&id).into_iter().find(|id| {
+            self.find_child(id)
+                .is_some_and(|node| node.is_scroll_container())
+        })
+    }
+}
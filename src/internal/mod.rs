@@ -1,18 +1,38 @@
+mod focus;
+mod hit;
 mod render;
 
+pub use focus::FocusDirection;
 use ratatui::layout::Size;
 use ratatui::widgets::StatefulWidget;
 pub use render::RenderLoop;
 use tui_scrollview::{ScrollView, ScrollViewState, ScrollbarVisibility};
 
-use crate::event::{EventContext, EventListener, EventType, ListenerId};
+use crate::event::{EventContext, EventListener, EventPhase, EventType, ListenerId, StyleUpdate};
 use crate::layout::shrink_and_offset_border;
-use crate::style::{Overflow, Style};
-use crate::widget::Widget;
+use crate::style::{NodeState, Overflow, PositionMode, Style};
+use crate::stylesheet::{Declaration, SelectorTarget, Stylesheet};
+use crate::theme::Theme;
+use crate::widget::{Widget, WidgetKind};
 use ratatui::{buffer::Buffer, layout::Rect};
 use std::collections::HashMap;
 use std::sync::Arc;
 
+/// Per-frame state shared by every node while rendering.
+pub struct RenderContext<'a> {
+    pub focused_id: Option<&'a str>,
+    pub theme: &'a Theme,
+}
+
+/// Nodes currently in an interaction state, used to compute pseudo-state styles.
+pub struct InteractionState<'a> {
+    pub focused_id: Option<&'a str>,
+    /// The hovered node and its ancestors.
+    pub hovered: &'a [String],
+    /// The pressed node and its ancestors.
+    pub active: &'a [String],
+}
+
 /// Internal node in the UI tree.
 ///
 /// Nodes form a hierarchical structure similar to DOM. Each node can optionally
@@ -24,7 +44,17 @@ use std::sync::Arc;
 pub struct Node {
     pub id: String,
     pub parent_id: Option<String>,
+    /// Computed style (defaults + stylesheet rules + inline style), used for layout and render.
     pub style: Style,
+    /// Style set directly on the node via `update_style`.
+    pub inline_style: Style,
+    /// Style the node was created with; inline properties equal to it don't
+    /// override stylesheet rules.
+    pub base_style: Style,
+    /// Widget kind, `None` for containers.
+    pub kind: Option<WidgetKind>,
+    /// Stylesheet classes attached to this node.
+    pub classes: Vec<String>,
     pub area: Rect,         // Allocated area from layout
     pub content_area: Rect, // Actual content area (for hit testing)
     pub scroll_state: Option<ScrollViewState>,
@@ -32,6 +62,8 @@ pub struct Node {
     pub children: Vec<Node>,
     /// Event listeners attached to this node.
     pub listeners: HashMap<EventType, HashMap<ListenerId, EventListener>>,
+    /// Capture-phase listeners, run on the way down from the root.
+    pub capture_listeners: HashMap<EventType, HashMap<ListenerId, EventListener>>,
 }
 
 impl Node {
@@ -40,17 +72,32 @@ impl Node {
             id,
             parent_id: None,
             style: Style::new().column(),
+            inline_style: Style::new().column(),
+            base_style: Style::new().column(),
+            kind: None,
+            classes: vec![],
             area: Rect::default(),
             content_area: Rect::default(),
             scroll_state: None,
             widget: None,
             children: vec![],
             listeners: HashMap::new(),
+            capture_listeners: HashMap::new(),
         }
     }
 
     /// Layout the tree starting from this node.
     pub fn layout(&mut self, parent_area: Rect) {
+        // display: none - no space for this node or its subtree
+        if !self.style.is_displayed() {
+            self.area = Rect::default();
+            self.content_area = Rect::default();
+            for child in &mut self.children {
+                child.layout(Rect::default());
+            }
+            return;
+        }
+
         // Calculate this node's area based on position_mode
         self.area = if self.style.position_mode == crate::style::PositionMode::Floating {
             // Floating: use x, y, width, height from style
@@ -89,18 +136,46 @@ impl Node {
         }
     }
 
-    pub fn render(&mut self, buffer: &mut Buffer, focused_id: Option<&str>) {
+    pub fn render(&mut self, buffer: &mut Buffer, ctx: &RenderContext) {
+        // Floating nodes paint in a global layer above the tree, in the same
+        // order hit testing uses
+        let layers = self.floating_layers();
+        self.paint(buffer, ctx, true);
+        for id in layers {
+            if let Some(node) = self.find_child_mut(&id) {
+                node.paint(buffer, ctx, true);
+            }
+        }
+    }
+
+    /// Paint this node and its subtree, skipping floating children lifted
+    /// into the global layer when `lift` is set.
+    fn paint(&mut self, buffer: &mut Buffer, ctx: &RenderContext, lift: bool) {
+        // Hidden nodes draw nothing, including their children
+        if !self.style.is_shown() {
+            return;
+        }
+
+        // Theme colors are resolved at draw time so theme switches need no node updates
+        let style = self.style.resolve(ctx.theme);
+
         // If this node has a background color, clear the area first to cover underlying content
-        if self.style.bg_color.is_some() {
+        if style.bg_color.is_some() {
             use ratatui::widgets::Widget as RatatuiWidget;
             ratatui::widgets::Clear.render(self.area, buffer);
         }
 
-        // Render background if bg_color is set
-        if let Some(bg_color) = self.style.bg_color {
+        // Render background and foreground colors if set
+        if style.bg_color.is_some() || style.fg_color.is_some() {
             use ratatui::widgets::Widget as RatatuiWidget;
-            let block = ratatui::widgets::Block::default()
-                .style(ratatui::style::Style::default().bg(bg_color.into()));
+            let mut color_style = ratatui::style::Style::default();
+            if let Some(bg_color) = style.bg_color {
+                color_style = color_style.bg(bg_color.to_ratatui(ctx.theme));
+            }
+            if let Some(fg_color) = style.fg_color {
+                color_style = color_style.fg(fg_color.to_ratatui(ctx.theme));
+            }
+            let block = ratatui::widgets::Block::default().style(color_style);
             block.render(self.area, buffer);
         }
 
@@ -121,10 +196,28 @@ impl Node {
                     BorderType::Thick => RatatuiBorderType::Thick,
                 };
 
-                let block = Block::default()
+                let is_focused = ctx.focused_id == Some(self.id.as_str());
+                let mut block = Block::default()
                     .border_type(ratatui_border_type)
-                    .borders(ratatui::widgets::Borders::ALL)
+                    .borders(style.border_sides.into())
                     .merge_borders(MergeStrategy::Exact);
+                if let Some(color) = style.border_color_for(is_focused) {
+                    block = block.border_style(
+                        ratatui::style::Style::default().fg(color.to_ratatui(ctx.theme)),
+                    );
+                }
+                if let Some(title) = &style.title {
+                    block = block.title_top(
+                        ratatui::text::Line::from(title.as_str())
+                            .alignment(style.title_align.into()),
+                    );
+                }
+                if let Some(footer) = &style.footer {
+                    block = block.title_bottom(
+                        ratatui::text::Line::from(footer.as_str())
+                            .alignment(style.footer_align.into()),
+                    );
+                }
 
                 block.render(self.area, buffer);
             }
@@ -136,7 +229,19 @@ impl Node {
                 if self.scroll_state.is_none() {
                     self.scroll_state = Some(ScrollViewState::new());
                 }
-                let size = Size::new(self.area.width, self.area.height);
+                // Children are laid out in the ScrollView's local coordinates
+                let viewport = shrink_and_offset_border(&self.style, self.area);
+                let (width, height) = self
+                    .children
+                    .iter()
+                    .filter(|child| child.style.is_shown())
+                    .fold((viewport.width, viewport.height), |(w, h), child| {
+                        (
+                            w.max(child.area.right() + self.style.padding.right),
+                            h.max(child.area.bottom() + self.style.padding.bottom),
+                        )
+                    });
+                let size = Size::new(width, height);
                 match self.style.overflow {
                     Overflow::Hidden => {
                         scroll_view = Some(
@@ -169,51 +274,45 @@ impl Node {
         // Render widget if present
         if let Some(widget) = &self.widget {
             // Check if this node is focused
-            let is_focused = focused_id.map_or(false, |fid| fid == self.id);
-            widget.render(widget_buffer, self.area, &self.style, is_focused);
+            let is_focused = ctx.focused_id == Some(self.id.as_str());
+            widget.render(widget_buffer, self.area, &style, is_focused);
         }
 
         // Render children sorted by z-index (higher z-index renders on top)
-        let mut children: Vec<_> = self.children.iter_mut().collect();
-        children.sort_by_key(|child| child.style.z_index);
-        for child in children {
-            child.render(widget_buffer, focused_id);
+        let child_lift = lift && !self.is_scroll_container();
+        for i in self.paint_order() {
+            if child_lift && self.children[i].style.position_mode == PositionMode::Floating {
+                continue;
+            }
+            self.children[i].paint(widget_buffer, ctx, child_lift);
         }
 
         if let Some(v) = scroll_view {
             let area = shrink_and_offset_border(&self.style, self.area);
-            v.render(area, buffer, &mut self.scroll_state.as_mut().unwrap());
+            v.render(area, buffer, self.scroll_state.as_mut().unwrap());
         }
     }
 
-    /// Find the widget at the given position.
-    /// Returns the id of the deepest child that contains the point.
-    pub fn find_widget_at(&self, x: u16, y: u16) -> Option<String> {
-        if !self.area.contains((x, y).into()) {
-            return None;
-        }
-
-        for child in &self.children {
-            if let Some(id) = child.find_widget_at(x, y) {
-                return Some(id);
-            }
-        }
+    /// Trigger all listeners for the given event type.
+    pub fn trigger_event(&self, event_type: &EventType, ctx: EventContext) {
+        Self::call_listeners(&self.listeners, event_type, ctx);
+    }
 
-        // Check if this node has a widget
-        if self.widget.is_some() {
-            Some(self.id.clone())
-        } else if self.children.is_empty() {
-            // Container without widget and children
-            Some(self.id.clone())
-        } else {
-            None
-        }
+    /// Trigger capture-phase listeners for the given event type.
+    pub fn trigger_capture(&self, event_type: &EventType, ctx: EventContext) {
+        Self::call_listeners(&self.capture_listeners, event_type, ctx);
     }
 
-    /// Trigger all listeners for the given event type.
-    pub fn trigger_event(&self, event_type: &EventType, ctx: EventContext) {
-        if let Some(listeners) = self.listeners.get(event_type) {
+    fn call_listeners(
+        listeners: &HashMap<EventType, HashMap<ListenerId, EventListener>>,
+        event_type: &EventType,
+        ctx: EventContext,
+    ) {
+        if let Some(listeners) = listeners.get(event_type) {
             for listener in listeners.values() {
+                if ctx.is_propagation_stopped() {
+                    break;
+                }
                 listener(ctx.clone());
             }
         }
@@ -235,39 +334,30 @@ impl Node {
         &mut self,
         parent_id: &str,
         id: String,
+        kind: WidgetKind,
         widget: Box<dyn Widget>,
         style: Style,
     ) {
         if let Some(parent) = self.find_child_mut(parent_id) {
-            let parent_id_owned = parent.id.clone();
-            parent.children.push(Node {
-                id,
-                parent_id: Some(parent_id_owned),
-                style,
-                area: Rect::default(),
-                content_area: Rect::default(),
-                scroll_state: None,
-                widget: Some(widget),
-                children: vec![],
-                listeners: HashMap::new(),
-            });
+            let mut node = Node::new(id);
+            node.parent_id = Some(parent.id.clone());
+            node.style = style.clone();
+            node.inline_style = style.clone();
+            node.base_style = style;
+            node.kind = Some(kind);
+            node.widget = Some(widget);
+            parent.children.push(node);
         }
     }
 
     pub fn add_container(&mut self, parent_id: &str, id: String, style: Style) {
         if let Some(parent) = self.find_child_mut(parent_id) {
-            let parent_id_owned = parent.id.clone();
-            parent.children.push(Node {
-                id,
-                parent_id: Some(parent_id_owned),
-                style,
-                area: Rect::default(),
-                content_area: Rect::default(),
-                scroll_state: None,
-                widget: None,
-                children: vec![],
-                listeners: HashMap::new(),
-            });
+            let mut node = Node::new(id);
+            node.parent_id = Some(parent.id.clone());
+            node.style = style.clone();
+            node.inline_style = style.clone();
+            node.base_style = style;
+            parent.children.push(node);
         }
     }
 
@@ -276,9 +366,16 @@ impl Node {
             self.widget = None;
             self.children.clear();
             self.listeners.clear();
+            self.capture_listeners.clear();
             return;
         }
+        let before = self.children.len();
         self.children.retain(|child| child.id != id);
+        if self.children.len() == before {
+            for child in &mut self.children {
+                child.remove_child(id);
+            }
+        }
     }
 
     pub fn update_widget_box(&mut self, id: &str, widget: Box<dyn Widget>) {
@@ -287,12 +384,126 @@ impl Node {
         }
     }
 
-    pub fn update_style(&mut self, id: &str, style: Style) {
+    pub fn update_style(&mut self, id: &str, update: StyleUpdate) {
         if let Some(node) = self.find_child_mut(id) {
-            node.style = style;
+            update(&mut node.inline_style);
         }
     }
 
+    /// Show (`Some(true)`), hide (`Some(false)`) or toggle (`None`) a node via `display`.
+    pub fn set_displayed(&mut self, id: &str, shown: Option<bool>) {
+        if let Some(node) = self.find_child_mut(id) {
+            let shown = shown.unwrap_or(!node.inline_style.is_displayed());
+            node.inline_style.display = if shown {
+                crate::style::Display::Flex
+            } else {
+                crate::style::Display::None
+            };
+        }
+    }
+
+    /// Whether the node and all its ancestors are shown.
+    pub fn is_node_shown(&self, id: &str) -> bool {
+        let chain = self.ancestor_chain(id);
+        !chain.is_empty()
+            && chain
+                .iter()
+                .all(|id| self.find_child(id).is_some_and(|n| n.style.is_shown()))
+    }
+
+    /// Add, remove or toggle a class on a node.
+    ///
+    /// `enable`: `Some(true)` adds, `Some(false)` removes, `None` toggles.
+    pub fn set_class(&mut self, id: &str, class: &str, enable: Option<bool>) {
+        if let Some(node) = self.find_child_mut(id) {
+            let present = node.classes.iter().any(|c| c == class);
+            match (enable.unwrap_or(!present), present) {
+                (true, false) => node.classes.push(class.to_string()),
+                (false, true) => node.classes.retain(|c| c != class),
+                _ => {}
+            }
+        }
+    }
+
+    /// Recompute `style` for this subtree.
+    ///
+    /// Cascade order, later steps winning: `base_style` (with its own state
+    /// overrides), stylesheet rules, inline properties that differ from
+    /// `base_style`, then inline state overrides.
+    pub fn compute_styles(
+        &mut self,
+        stylesheet: &Stylesheet,
+        interaction: &InteractionState,
+        path: &mut Vec<SelectorTarget>,
+    ) {
+        let mut states = Vec::new();
+        if interaction.hovered.contains(&self.id) {
+            states.push(NodeState::Hover);
+        }
+        if interaction.focused_id == Some(self.id.as_str()) {
+            states.push(NodeState::Focus);
+        }
+        if interaction.active.contains(&self.id) {
+            states.push(NodeState::Active);
+        }
+        if self.inline_style.disabled {
+            states.push(NodeState::Disabled);
+        }
+
+        let mut style = self.base_style.clone();
+        for state in &states {
+            for declaration in self.base_style.states.get(*state) {
+                declaration.apply(&mut style);
+            }
+        }
+
+        path.push(SelectorTarget {
+            id: self.id.clone(),
+            kind: self.kind,
+            classes: self.classes.clone(),
+            states: states.clone(),
+        });
+        stylesheet.apply(path, &mut style);
+
+        // Inline properties only win where they were actually changed
+        let inline = Declaration::all(&self.inline_style);
+        let base = Declaration::all(&self.base_style);
+        for (declaration, default) in inline.iter().zip(&base) {
+            if declaration != default {
+                declaration.apply(&mut style);
+            }
+        }
+        style.states = self.inline_style.states.clone();
+        for state in &states {
+            let defaults = self.base_style.states.get(*state);
+            for declaration in self.inline_style.states.get(*state) {
+                if !defaults.contains(declaration) {
+                    declaration.apply(&mut style);
+                }
+            }
+        }
+        self.style = style;
+
+        for child in &mut self.children {
+            child.compute_styles(stylesheet, interaction, path);
+        }
+        path.pop();
+    }
+
+    /// Ids of the node and all its ancestors (node first).
+    pub fn ancestor_chain(&self, id: &str) -> Vec<String> {
+        let mut chain = Vec::new();
+        let mut current = Some(id.to_string());
+        while let Some(current_id) = current {
+            let Some(node) = self.find_child(&current_id) else {
+                break;
+            };
+            current = node.parent_id.clone();
+            chain.push(current_id);
+        }
+        chain
+    }
+
     /// Calculate the content size of this node (including children).
     pub fn calculate_content_size(&self, available_area: Rect) -> (u16, u16) {
         crate::layout::calculate_content_size(self, available_area)
@@ -307,11 +518,17 @@ impl Node {
         event_type: EventType,
         listener: EventListener,
         listener_id: ListenerId,
+        capture: bool,
     ) {
         if self.id == target_id {
-            self.listeners
+            let listeners = if capture {
+                &mut self.capture_listeners
+            } else {
+                &mut self.listeners
+            };
+            listeners
                 .entry(event_type)
-                .or_insert_with(HashMap::new)
+                .or_default()
                 .insert(listener_id, listener);
         } else {
             // Recursively search in children
@@ -321,6 +538,7 @@ impl Node {
                     event_type.clone(),
                     Arc::clone(&listener),
                     listener_id,
+                    capture,
                 );
             }
         }
@@ -328,7 +546,11 @@ impl Node {
 
     /// Remove an event listener by its ID.
     pub fn remove_event_listener(&mut self, listener_id: ListenerId) {
-        for listeners in self.listeners.values_mut() {
+        for listeners in self
+            .listeners
+            .values_mut()
+            .chain(self.capture_listeners.values_mut())
+        {
             listeners.remove(&listener_id);
         }
         for child in &mut self.children {
@@ -336,54 +558,40 @@ impl Node {
         }
     }
 
-    /// Trigger event with bubbling (from target up to root).
+    /// Dispatch an event along the target's ancestors: capture listeners from
+    /// the root down to the target, then bubbling listeners from the target up.
+    ///
+    /// The context's flags are shared, so the caller can check
+    /// `is_default_prevented` on its own copy afterwards.
     pub fn trigger_event_with_bubble(&self, event_type: &EventType, mut ctx: EventContext) {
-        let original_target = ctx.target_id.clone();
-        let mut current_id = original_target.clone();
-
-        loop {
-            if ctx.propagation_stopped {
-                break;
+        let path = self.ancestor_chain(&ctx.target_id);
+
+        // Capture phase (root -> target)
+        ctx.phase = EventPhase::Capture;
+        for id in path.iter().skip(1).rev() {
+            if let Some(node) = self.find_child(id) {
+                ctx.current_target_id = id.clone();
+                node.trigger_capture(event_type, ctx.clone());
             }
-
-            if let Some(node) = self.find_child(&current_id) {
-                ctx.current_target_id = current_id.clone();
-                node.trigger_event(event_type, ctx.clone());
-
-                // Bubble up to parent
-                if let Some(parent_id) = &node.parent_id {
-                    current_id = parent_id.clone();
-                } else {
-                    break; // Reached root
-                }
-            } else {
-                break;
-            }
-        }
-    }
-
-    /// Find a scrollview container at the given position.
-    /// Returns the deepest node with overflow != Visible that contains the point.
-    pub fn find_scrollview_at(&self, x: u16, y: u16) -> Option<String> {
-        if !self.area.contains((x, y).into()) {
-            return None;
         }
 
-        // Check children first (deepest first)
-        for child in &self.children {
-            if let Some(id) = child.find_scrollview_at(x, y) {
-                return Some(id);
+        // Target: capture listeners, then regular ones
+        let Some(target) = path.first().and_then(|id| self.find_child(id)) else {
+            return;
+        };
+        ctx.current_target_id = target.id.clone();
+        ctx.phase = EventPhase::Target;
+        target.trigger_capture(event_type, ctx.clone());
+        target.trigger_event(event_type, ctx.clone());
+
+        // Bubble phase (target -> root)
+        ctx.phase = EventPhase::Bubble;
+        for id in path.iter().skip(1) {
+            if let Some(node) = self.find_child(id) {
+                ctx.current_target_id = id.clone();
+                node.trigger_event(event_type, ctx.clone());
             }
         }
-
-        // Check if this node is a scrollview container
-        if self.style.overflow != Overflow::Visible
-            && (self.widget.is_none() || !self.children.is_empty())
-        {
-            Some(self.id.clone())
-        } else {
-            None
-        }
     }
 
     /// Handle scroll event by updating scroll_state.
@@ -409,7 +617,7 @@ impl Node {
     }
 
     /// Find a child node by id.
-    fn find_child(&self, id: &str) -> Option<&Node> {
+    pub fn find_child(&self, id: &str) -> Option<&Node> {
         if self.id == id {
             return Some(self);
         }
@@ -1,29 +1,167 @@
 use crossterm::{
     ExecutableCommand,
-    event::{DisableMouseCapture, EnableMouseCapture, KeyEvent, KeyModifiers, MouseEventKind},
+    event::{
+        DisableMouseCapture, EnableMouseCapture, KeyCode, KeyEvent, KeyModifiers, MouseButton,
+        MouseEvent, MouseEventKind,
+    },
 };
+use parking_lot::Mutex;
 use ratatui::{Terminal, backend::CrosstermBackend};
 use tokio::sync::mpsc;
 
-use crate::event::{Event, EventContext, EventType, ListenerId, UiMessage};
-use crate::internal::Node;
+use crate::animation::{self, Animation, Easing};
+use crate::commands::UiCommands;
+use crate::event::{
+    CustomEvent, Event, EventContext, EventListener, EventType, ListenerId, UiMessage, WidgetEvent,
+};
+use crate::internal::{FocusDirection, InteractionState, Node, RenderContext};
+use crate::key::{KeyBinding, KeySequence};
+use crate::keymap::{KeyContext, Keymap, KeymapEntry};
+use crate::style::Style;
+use crate::stylesheet::Stylesheet;
+use crate::theme::Theme;
+use crate::timer::{TimerCallback, TimerContext, TimerId, TimerKind};
+use crate::widget::WidgetKind;
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio_stream::{Stream, StreamExt};
+
+/// Minimum time between animation frames (about 60 per second).
+const FRAME_INTERVAL: Duration = Duration::from_millis(16);
+
+/// What a completed key sequence runs.
+enum ChordTarget {
+    Listener(EventListener),
+    Action(String),
+}
+
+/// An open modal and the focus to restore when it closes.
+struct ModalScope {
+    id: String,
+    previous_focus: Option<String>,
+}
+
+/// A mouse button held down since a press on `id`.
+struct DragState {
+    id: String,
+    button: MouseButton,
+    origin: (u16, u16),
+    /// Position of the previous drag event.
+    last: (u16, u16),
+    /// Whether DragStart has fired.
+    started: bool,
+}
+
+/// A pending timeout or interval.
+struct Timer {
+    id: TimerId,
+    due: Instant,
+    /// Period for intervals; timeouts are dropped after firing.
+    interval: Option<Duration>,
+    callback: TimerCallback,
+}
+
+/// A style animation in progress on `node_id`.
+struct RunningAnimation {
+    id: TimerId,
+    node_id: String,
+    from: Style,
+    to: Style,
+    start: Instant,
+    duration: Duration,
+    easing: Easing,
+    on_complete: Option<TimerCallback>,
+}
+
+/// The previous press, for double and triple click detection.
+#[derive(Clone, Copy)]
+struct LastClick {
+    button: MouseButton,
+    position: (u16, u16),
+    at: Instant,
+    count: u8,
+}
 
 /// Internal render loop state.
 pub struct RenderLoop {
     root: Node,
     focused_id: Option<String>,
+    /// Copy of `focused_id` readable from `Document::focused_id`.
+    focus_mirror: Arc<Mutex<Option<String>>>,
+    /// Move focus with arrow keys when the focused widget doesn't handle them.
+    spatial_navigation: bool,
+    /// Open modals, topmost last.
+    modal_stack: Vec<ModalScope>,
+    /// Deepest node under the mouse cursor.
+    hovered_id: Option<String>,
+    /// Node the left mouse button was pressed on (until released).
+    pressed_id: Option<String>,
+    /// Widget that took the last left press; a release over it is offered
+    /// to it too (e.g. to press a button).
+    release_target: Option<String>,
+    /// Press being tracked for drag events.
+    drag: Option<DragState>,
+    last_click: Option<LastClick>,
+    /// Max time and distance between clicks of a double/triple click.
+    multi_click_interval: Duration,
+    multi_click_distance: u16,
     mouse_capture_enabled: bool,
+    theme: Theme,
+    stylesheet: Stylesheet,
+    /// Set when computed styles need to be recalculated before the next layout.
+    styles_dirty: bool,
     global_listeners: HashMap<EventType, Vec<(ListenerId, crate::event::EventListener)>>,
+    /// Key sequences bound with `Document::bind`.
+    key_bindings: Vec<(ListenerId, KeySequence, EventListener)>,
+    /// Keys of a sequence typed so far, and when the last one was pressed.
+    pending_keys: Vec<KeyEvent>,
+    pending_since: Option<Instant>,
+    /// How long to wait for the next key of a sequence.
+    chord_timeout: Duration,
+    /// Action bindings by focus context.
+    keymap: Keymap,
+    action_listeners: HashMap<String, Vec<(ListenerId, EventListener)>>,
+    /// Changes queued by listeners through `ctx.ui()`.
+    commands: UiCommands,
+    timers: Vec<Timer>,
+    /// Callbacks waiting for the next animation frame.
+    frame_callbacks: Vec<(TimerId, TimerCallback)>,
+    last_frame: Instant,
+    animations: Vec<RunningAnimation>,
 }
 
 impl RenderLoop {
-    pub fn new() -> Self {
+    pub fn new(focus_mirror: Arc<Mutex<Option<String>>>) -> Self {
         RenderLoop {
             root: Node::new("root".to_string()),
             focused_id: None,
+            focus_mirror,
+            spatial_navigation: false,
+            modal_stack: Vec::new(),
+            hovered_id: None,
+            pressed_id: None,
+            release_target: None,
+            drag: None,
+            last_click: None,
+            multi_click_interval: Duration::from_millis(500),
+            multi_click_distance: 1,
             mouse_capture_enabled: true, // Default: enabled
+            theme: Theme::default(),
+            stylesheet: Stylesheet::new(),
+            styles_dirty: true,
             global_listeners: HashMap::new(),
+            key_bindings: Vec::new(),
+            pending_keys: Vec::new(),
+            pending_since: None,
+            chord_timeout: Duration::from_secs(1),
+            keymap: Keymap::new(),
+            action_listeners: HashMap::new(),
+            commands: UiCommands::default(),
+            timers: Vec::new(),
+            frame_callbacks: Vec::new(),
+            last_frame: Instant::now(),
+            animations: Vec::new(),
         }
     }
 
@@ -31,167 +169,1064 @@ impl RenderLoop {
         mut terminal: Terminal<CrosstermBackend<std::io::Stdout>>,
         mut ui_rx: mpsc::Receiver<UiMessage>,
         event_tx: mpsc::Sender<Event>,
+        focus_mirror: Arc<Mutex<Option<String>>>,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        let mut state = Self::new();
+        let mut state = Self::new(focus_mirror);
+        let mut terminal_events = crossterm::event::EventStream::new();
 
         loop {
+            // Timers and animation frames that are due run before layout
+            state.run_timers(Instant::now());
+
+            // Resolve a key sequence left waiting past the chord timeout
+            state.flush_chord();
+
+            // Apply changes listeners and timers made since the last frame
+            state.apply_commands();
+
+            // Cascade stylesheet rules into computed styles
+            if state.styles_dirty {
+                state.compute_styles();
+            }
+
             // Render the tree
             let _ = terminal.draw(|f| {
                 // First calculate layout based on screen size
                 let screen_area = f.area();
                 state.root.layout(screen_area);
 
-                // Then render with focus state
+                // Then render with focus state and the active theme
+                let ctx = RenderContext {
+                    focused_id: state.focused_id.as_deref(),
+                    theme: &state.theme,
+                };
                 let buffer = f.buffer_mut();
-                state.root.render(buffer, state.focused_id.as_deref());
+                state.root.render(buffer, &ctx);
             });
 
-            // Handle UI commands
-            while let Ok(msg) = ui_rx.try_recv() {
-                state.handle_ui_msg(msg);
-            }
-
-            // Poll terminal events and dispatch
-            if let Ok(true) = crossterm::event::poll(std::time::Duration::ZERO) {
-                if let Ok(event) = crossterm::event::read() {
-                    match event {
-                        crossterm::event::Event::Key(key) => {
-                            // focused widget (if any)
-                            if let Some(ref focused_id) = state.focused_id {
-                                if let Some(node) = state.root.find_child_mut(focused_id) {
-                                    if let Some(widget) = &mut node.widget {
-                                        widget.handle_key(key);
-                                    }
-
-                                    // Trigger key press event listeners on the node with bubbling
-                                    let ctx = EventContext {
-                                        event_type: EventType::KeyPress(key.code),
-                                        target_id: focused_id.clone(),
-                                        current_target_id: focused_id.clone(),
-                                        mouse_x: None,
-                                        mouse_y: None,
-                                        scroll_delta: None,
-                                        key_code: Some(key.code),
-                                        propagation_stopped: false,
-                                    };
-                                    state.root.trigger_event_with_bubble(
-                                        &EventType::KeyPress(key.code),
-                                        ctx,
-                                    );
-                                }
-                            }
-
-                            // Global listeners (triggered after bubbling)
-                            state.trigger_global_listeners(&EventType::KeyPress(key.code), key);
-
-                            // Forward to user
-                            let _ = event_tx.try_send(Event::Key(key));
-                        }
-                        crossterm::event::Event::Mouse(mouse) => {
-                            // Forward to user
-                            let _ = event_tx.try_send(Event::Mouse(mouse.clone()));
-
-                            // Handle click for focus
-                            if mouse.kind
-                                == MouseEventKind::Down(crossterm::event::MouseButton::Left)
-                            {
-                                let clicked_id = state.root.find_widget_at(mouse.column, mouse.row);
-
-                                // Update focus
-                                if clicked_id.as_ref() != state.focused_id.as_ref() {
-                                    // Blur old
-                                    if let Some(old_id) = state.focused_id.take() {
-                                        let ctx = EventContext {
-                                            event_type: EventType::Blur,
-                                            target_id: old_id.clone(),
-                                            current_target_id: old_id.clone(),
-                                            mouse_x: None,
-                                            mouse_y: None,
-                                            scroll_delta: None,
-                                            key_code: None,
-                                            propagation_stopped: false,
-                                        };
-                                        state.root.trigger_event_with_bubble(&EventType::Blur, ctx);
-                                    }
-
-                                    // Focus new (if clicked on a widget)
-                                    if let Some(ref id) = clicked_id {
-                                        state.focused_id = Some(id.clone());
-                                        let ctx = EventContext {
-                                            event_type: EventType::Focus,
-                                            target_id: id.clone(),
-                                            current_target_id: id.clone(),
-                                            mouse_x: Some(mouse.column),
-                                            mouse_y: Some(mouse.row),
-                                            scroll_delta: None,
-                                            key_code: None,
-                                            propagation_stopped: false,
-                                        };
-                                        state
-                                            .root
-                                            .trigger_event_with_bubble(&EventType::Focus, ctx);
-                                    }
-                                }
-
-                                // Trigger click listeners with bubbling (if clicked on a widget)
-                                if let Some(ref id) = clicked_id {
-                                    let ctx = EventContext {
-                                        event_type: EventType::Click,
-                                        target_id: id.clone(),
-                                        current_target_id: id.clone(),
-                                        mouse_x: Some(mouse.column),
-                                        mouse_y: Some(mouse.row),
-                                        scroll_delta: None,
-                                        key_code: None,
-                                        propagation_stopped: false,
-                                    };
-                                    state.root.trigger_event_with_bubble(&EventType::Click, ctx);
-                                }
-                            }
-
-                            // Dispatch to element under mouse
-                            state.dispatch_mouse_event(mouse);
-                        }
-                        crossterm::event::Event::Resize(w, h) => {
-                            // Forward to user
-                            let _ = event_tx.try_send(Event::Resize(w, h));
-                        }
-                        _ => {}
+            // Every sender is gone: the Document has been dropped
+            if !state
+                .wait(&mut ui_rx, &mut terminal_events, &event_tx)
+                .await
+            {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Sleep until a UI message, a queued command, a terminal event or the
+    /// next deadline, and handle it. Returns false once every `ui_tx` sender
+    /// is gone.
+    async fn wait<S>(
+        &mut self,
+        ui_rx: &mut mpsc::Receiver<UiMessage>,
+        terminal_events: &mut S,
+        event_tx: &mpsc::Sender<Event>,
+    ) -> bool
+    where
+        S: Stream<Item = std::io::Result<crossterm::event::Event>> + Unpin,
+    {
+        let deadline = self.next_deadline();
+        // Commands queued through `ctx.ui()`, e.g. from a spawned task
+        let commands = self.commands.clone();
+        tokio::select! {
+            msg = ui_rx.recv() => {
+                let Some(msg) = msg else {
+                    return false;
+                };
+                self.handle_ui_msg(msg);
+                while let Ok(msg) = ui_rx.try_recv() {
+                    self.handle_ui_msg(msg);
+                }
+            }
+            _ = commands.queued() => {}
+            Some(Ok(event)) = terminal_events.next() => {
+                self.handle_terminal_event(event, event_tx);
+            }
+            _ = async {
+                match deadline {
+                    Some(deadline) => tokio::time::sleep_until(deadline.into()).await,
+                    None => std::future::pending().await,
+                }
+            } => {}
+        }
+        true
+    }
+
+    /// Dispatch a terminal event and forward it to the user.
+    fn handle_terminal_event(
+        &mut self,
+        event: crossterm::event::Event,
+        event_tx: &mpsc::Sender<Event>,
+    ) {
+        match event {
+            crossterm::event::Event::Key(key) => {
+                self.handle_key_event(key);
+
+                // Forward to user
+                let _ = event_tx.try_send(Event::Key(key));
+            }
+            crossterm::event::Event::Mouse(mouse) => {
+                // Forward to user
+                let _ = event_tx.try_send(Event::Mouse(mouse));
+
+                self.handle_mouse_event(mouse);
+            }
+            crossterm::event::Event::Resize(w, h) => {
+                let ctx = EventContext {
+                    size: Some((w, h)),
+                    ..self.event_ctx(EventType::Resize, "root")
+                };
+                self.dispatch_root_event(ctx);
+
+                // Forward to user
+                let _ = event_tx.try_send(Event::Resize(w, h));
+            }
+            crossterm::event::Event::Paste(text) => {
+                self.handle_paste(&text);
+
+                // Forward to user
+                let _ = event_tx.try_send(Event::Paste(text));
+            }
+            crossterm::event::Event::FocusGained => {
+                self.dispatch_root_event(self.event_ctx(EventType::TerminalFocus, "root"));
+                let _ = event_tx.try_send(Event::FocusGained);
+            }
+            crossterm::event::Event::FocusLost => {
+                self.dispatch_root_event(self.event_ctx(EventType::TerminalBlur, "root"));
+                let _ = event_tx.try_send(Event::FocusLost);
+            }
+        }
+    }
+
+    /// Run timers that are due, then animation frame callbacks if a frame is due.
+    fn run_timers(&mut self, now: Instant) {
+        let (mut due, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut self.timers)
+            .into_iter()
+            .partition(|timer| timer.due <= now);
+        self.timers = pending;
+        due.sort_by_key(|timer| timer.due);
+        for mut timer in due {
+            let ctx = self.timer_ctx(timer.id, now);
+            (timer.callback)(&ctx);
+            if let Some(interval) = timer.interval {
+                // Skip ticks missed while busy rather than firing them in a burst
+                timer.due += interval;
+                if timer.due <= now {
+                    timer.due = now + interval;
+                }
+                self.timers.push(timer);
+            }
+        }
+
+        if self.wants_frame() && now >= self.last_frame + FRAME_INTERVAL {
+            self.last_frame = now;
+            self.step_animations(now);
+            // Frames requested from these callbacks run on the next frame
+            for (id, mut callback) in std::mem::take(&mut self.frame_callbacks) {
+                callback(&self.timer_ctx(id, now));
+            }
+        }
+    }
+
+    /// Whether anything is waiting for the next animation frame.
+    fn wants_frame(&self) -> bool {
+        !self.frame_callbacks.is_empty() || !self.animations.is_empty()
+    }
+
+    /// Move every animation to its position at `now`, finishing completed ones.
+    fn step_animations(&mut self, now: Instant) {
+        let mut finished = Vec::new();
+        for animation in std::mem::take(&mut self.animations) {
+            let Some(node) = self.root.find_child_mut(&animation.node_id) else {
+                // The node was removed
+                continue;
+            };
+            let elapsed = now.duration_since(animation.start);
+            // Only the animated properties are written, so changes made to
+            // other properties while the animation runs are kept
+            if elapsed >= animation.duration {
+                animation::apply_changed(
+                    &mut node.inline_style,
+                    &animation.from,
+                    &animation.to,
+                    &animation.to,
+                );
+                finished.push(animation);
+            } else {
+                let t = elapsed.as_secs_f32() / animation.duration.as_secs_f32();
+                let frame = animation::interpolate(
+                    &animation.from,
+                    &animation.to,
+                    animation.easing.apply(t),
+                    &self.theme,
+                );
+                animation::apply_changed(
+                    &mut node.inline_style,
+                    &animation.from,
+                    &animation.to,
+                    &frame,
+                );
+                self.animations.push(animation);
+            }
+            self.styles_dirty = true;
+        }
+
+        for animation in finished {
+            if let Some(mut on_complete) = animation.on_complete {
+                on_complete(&self.timer_ctx(animation.id, now));
+            }
+        }
+    }
+
+    /// Start animating a node from its current inline style, replacing any
+    /// animation already running on it.
+    fn start_animation(&mut self, node_id: String, timer_id: TimerId, animation: Animation) {
+        let Some(node) = self.root.find_child(&node_id) else {
+            return;
+        };
+        let from = node.inline_style.clone();
+        let mut to = from.clone();
+        (animation.apply)(&mut to);
+
+        self.animations.retain(|running| running.node_id != node_id);
+        self.animations.push(RunningAnimation {
+            id: timer_id,
+            node_id,
+            from,
+            to,
+            start: Instant::now(),
+            duration: animation.duration,
+            easing: animation.easing,
+            on_complete: animation.on_complete,
+        });
+    }
+
+    fn timer_ctx(&self, timer_id: TimerId, now: Instant) -> TimerContext {
+        TimerContext {
+            timer_id,
+            now,
+            ui: self.commands.clone(),
+        }
+    }
+
+    /// The earliest time the loop has to wake up without any input.
+    fn next_deadline(&self) -> Option<Instant> {
+        let timers = self.timers.iter().map(|timer| timer.due);
+        let frame = self.wants_frame().then(|| self.last_frame + FRAME_INTERVAL);
+        let chord = self.pending_since.map(|since| since + self.chord_timeout);
+        timers.chain(frame).chain(chord).min()
+    }
+
+    /// Apply UI commands queued by listeners, including any queued while
+    /// applying them (e.g. by Focus/Blur listeners).
+    fn apply_commands(&mut self) {
+        loop {
+            let commands = self.commands.take();
+            if commands.is_empty() {
+                break;
+            }
+            for msg in commands {
+                self.handle_ui_msg(msg);
+            }
+        }
+    }
+
+    /// Handle a key press: key bindings first, then listeners (capture and
+    /// bubble), then the default handling - the focused widget, and focus
+    /// navigation if the widget didn't use the key.
+    fn handle_key_event(&mut self, key: KeyEvent) {
+        if self.handle_chord(key) {
+            return;
+        }
+        self.dispatch_key(key);
+    }
+
+    /// Deliver a key that no binding consumed to listeners and the focused widget.
+    fn dispatch_key(&mut self, key: KeyEvent) {
+        // Dispatch from the focused node, or the root when nothing is focused
+        let event_type = EventType::KeyPress(key.into());
+        let target_id = self.focused_id.clone().unwrap_or_else(|| "root".into());
+        let ctx = self.key_ctx(key, target_id);
+        self.root
+            .trigger_event_with_bubble(&event_type, ctx.clone());
+
+        // Global listeners (triggered after bubbling)
+        if !ctx.is_propagation_stopped() {
+            self.trigger_global_listeners(&event_type, &ctx);
+        }
+
+        if ctx.is_default_prevented() {
+            return;
+        }
+
+        // focused widget (if any)
+        let mut handled = false;
+        if let Some(ref focused_id) = self.focused_id
+            && let Some(node) = self.root.find_child_mut(focused_id)
+            && let Some(widget) = &mut node.widget
+        {
+            handled = widget.handle_key(key);
+        }
+        if let Some(focused_id) = self.focused_id.clone() {
+            self.dispatch_widget_events(&focused_id);
+        }
+
+        if !handled {
+            self.navigate_focus(key);
+        }
+    }
+
+    /// Handle a bracketed paste: listeners first, then the focused widget
+    /// inserts the text in one step.
+    fn handle_paste(&mut self, text: &str) {
+        let target_id = self.focused_id.clone().unwrap_or_else(|| "root".into());
+        let ctx = EventContext {
+            value: Some(text.to_string()),
+            ..self.event_ctx(EventType::Paste, target_id)
+        };
+        self.root
+            .trigger_event_with_bubble(&EventType::Paste, ctx.clone());
+        if !ctx.is_propagation_stopped() {
+            self.trigger_global_listeners(&EventType::Paste, &ctx);
+        }
+        if ctx.is_default_prevented() {
+            return;
+        }
+
+        let Some(focused_id) = self.focused_id.clone() else {
+            return;
+        };
+        if let Some(node) = self.root.find_child_mut(&focused_id)
+            && let Some(widget) = &mut node.widget
+        {
+            widget.handle_paste(text);
+        }
+        self.dispatch_widget_events(&focused_id);
+    }
+
+    /// Fire a terminal-level event (resize, terminal focus) on the root and
+    /// then on global listeners.
+    fn dispatch_root_event(&self, ctx: EventContext) {
+        let event_type = ctx.event_type.clone();
+        self.root
+            .trigger_event_with_bubble(&event_type, ctx.clone());
+        if !ctx.is_propagation_stopped() {
+            self.trigger_global_listeners(&event_type, &ctx);
+        }
+    }
+
+    /// Dispatch the events a widget raised while handling input or a message
+    /// (Change, Submit).
+    fn dispatch_widget_events(&mut self, id: &str) {
+        let Some(events) = self
+            .root
+            .find_child_mut(id)
+            .and_then(|node| node.widget.as_mut())
+            .map(|widget| widget.take_events())
+        else {
+            return;
+        };
+        for event in events {
+            let event_type = event.event_type();
+            let (value, payload) = match event {
+                WidgetEvent::Change(value) | WidgetEvent::Submit(value) => (Some(value), None),
+                WidgetEvent::Press => (None, None),
+                WidgetEvent::Custom(event) => (None, event.payload),
+            };
+            let ctx = EventContext {
+                value,
+                payload,
+                ..self.event_ctx(event_type.clone(), id)
+            };
+            self.root.trigger_event_with_bubble(&event_type, ctx);
+        }
+    }
+
+    /// Dispatch a custom event from `target_id`, then to global listeners.
+    fn dispatch_custom(&self, target_id: String, event: CustomEvent) {
+        if self.root.find_child(&target_id).is_none() {
+            return;
+        }
+        let event_type = EventType::Custom(event.name);
+        let ctx = EventContext {
+            payload: event.payload,
+            ..self.event_ctx(event_type.clone(), target_id)
+        };
+        self.root
+            .trigger_event_with_bubble(&event_type, ctx.clone());
+        if !ctx.is_propagation_stopped() {
+            self.trigger_global_listeners(&event_type, &ctx);
+        }
+    }
+
+    /// Feed a key into the bound sequences. Returns true if the key was consumed,
+    /// either completing a sequence or waiting for the next key of one.
+    fn handle_chord(&mut self, key: KeyEvent) -> bool {
+        if self.key_bindings.is_empty() && self.keymap.is_empty() {
+            return false;
+        }
+        self.flush_chord();
+
+        self.pending_keys.push(key);
+        if !self.has_chord_prefix(&self.chord_bindings()) {
+            // Broken sequence: resolve the keys so far, then retry this key alone
+            self.pending_keys.pop();
+            self.fire_chord();
+            self.pending_keys = vec![key];
+            if !self.has_chord_prefix(&self.chord_bindings()) {
+                self.pending_keys.clear();
+                return false;
+            }
+        }
+
+        // Fire right away unless a longer sequence could still follow
+        let keys = self.pending_binding();
+        let extends = self
+            .chord_bindings()
+            .iter()
+            .any(|(seq, _)| seq.keys().len() > keys.len() && seq.starts_with(&keys));
+        if extends {
+            self.pending_since = Some(Instant::now());
+        } else {
+            self.fire_chord();
+        }
+        true
+    }
+
+    /// Resolve a pending sequence once the chord timeout has passed.
+    fn flush_chord(&mut self) {
+        if self
+            .pending_since
+            .is_some_and(|since| since.elapsed() >= self.chord_timeout)
+        {
+            self.fire_chord();
+        }
+    }
+
+    /// Run whatever is bound to the longest bound start of the pending keys,
+    /// then replay the keys after it (all of them if nothing is bound) as
+    /// ordinary key presses, so a sequence that didn't complete loses no input.
+    fn fire_chord(&mut self) {
+        let bindings = self.chord_bindings();
+        let pending = std::mem::take(&mut self.pending_keys);
+        self.pending_since = None;
+
+        let keys: Vec<KeyBinding> = pending.iter().map(|&key| key.into()).collect();
+        let matched = (1..=keys.len())
+            .rev()
+            .find(|&n| bindings.iter().any(|(seq, _)| seq.keys() == &keys[..n]))
+            .unwrap_or(0);
+        if matched > 0 {
+            let last = pending[matched - 1];
+            for (seq, target) in bindings {
+                if seq.keys() != &keys[..matched] {
+                    continue;
+                }
+                match target {
+                    ChordTarget::Listener(listener) => listener(self.key_ctx(last, "global")),
+                    ChordTarget::Action(action) => self.dispatch_action(&action, Some(last)),
+                }
+            }
+        }
+        for &key in &pending[matched..] {
+            self.dispatch_key(key);
+        }
+    }
+
+    /// Bindings that may claim the pending keys.
+    ///
+    /// A sequence starting with a plain character typed into a text field is
+    /// left to the field, unless the binding is in the field's own context
+    /// (its id or its widget kind).
+    fn chord_bindings(&self) -> Vec<(KeySequence, ChordTarget)> {
+        let typing = self
+            .pending_keys
+            .first()
+            .is_some_and(|&key| self.is_text_entry(key));
+        self.active_bindings(typing)
+    }
+
+    /// Whether `key` types a character into the focused Input or Textarea.
+    fn is_text_entry(&self, key: KeyEvent) -> bool {
+        let text_field = self
+            .focused_id
+            .as_deref()
+            .and_then(|id| self.root.find_child(id))
+            .is_some_and(|node| {
+                matches!(node.kind, Some(WidgetKind::Input | WidgetKind::Textarea))
+            });
+        text_field
+            && matches!(key.code, KeyCode::Char(_))
+            && (key.modifiers - KeyModifiers::SHIFT).is_empty()
+    }
+
+    /// `Document::bind` sequences plus the keymap bindings active for the
+    /// current focus. For keys bound in several contexts, only the most
+    /// specific context is kept. With `own_only`, only keymap bindings for the
+    /// focused node itself or its widget kind are returned.
+    fn active_bindings(&self, own_only: bool) -> Vec<(KeySequence, ChordTarget)> {
+        let mut bindings: Vec<_> = if own_only {
+            Vec::new()
+        } else {
+            self.key_bindings
+                .iter()
+                .map(|(_, seq, listener)| (seq.clone(), ChordTarget::Listener(listener.clone())))
+                .collect()
+        };
+
+        // Rank contexts: focused node, its kind, ancestors nearest first, global
+        let chain = self
+            .focused_id
+            .as_deref()
+            .map(|id| self.root.ancestor_chain(id))
+            .unwrap_or_default();
+        let focused_kind = self
+            .focused_id
+            .as_deref()
+            .and_then(|id| self.root.find_child(id))
+            .and_then(|node| node.kind);
+        let rank = |context: &KeyContext| match context {
+            KeyContext::Container(id) => chain
+                .iter()
+                .position(|c| c == id)
+                .map(|i| if i == 0 { 0 } else { i + 1 }),
+            KeyContext::Kind(kind) => (Some(*kind) == focused_kind).then_some(1),
+            KeyContext::Global => Some(usize::MAX),
+        };
+
+        let mut best: Vec<(usize, &KeymapEntry)> = Vec::new();
+        for entry in self.keymap.entries() {
+            let Some(r) = rank(&entry.context).filter(|&r| !own_only || r <= 1) else {
+                continue;
+            };
+            match best.iter_mut().find(|(_, e)| e.keys == entry.keys) {
+                Some(slot) if r < slot.0 => *slot = (r, entry),
+                Some(_) => {}
+                None => best.push((r, entry)),
+            }
+        }
+        bindings.extend(best.into_iter().map(|(_, entry)| {
+            (
+                entry.keys.clone(),
+                ChordTarget::Action(entry.action.clone()),
+            )
+        }));
+        bindings
+    }
+
+    fn pending_binding(&self) -> Vec<KeyBinding> {
+        self.pending_keys.iter().map(|&key| key.into()).collect()
+    }
+
+    fn has_chord_prefix(&self, bindings: &[(KeySequence, ChordTarget)]) -> bool {
+        let keys = self.pending_binding();
+        bindings.iter().any(|(seq, _)| seq.starts_with(&keys))
+    }
+
+    /// Run an action: bubble `EventType::Action` from the focused node,
+    /// then call the `on_action` listeners.
+    fn dispatch_action(&self, action: &str, key: Option<KeyEvent>) {
+        let event_type = EventType::Action(action.to_string());
+        let target_id = self.focused_id.as_deref().unwrap_or("global");
+        let ctx = EventContext {
+            key_code: key.map(|key| key.code),
+            key_event: key,
+            ..self.event_ctx(event_type.clone(), target_id)
+        };
+
+        if self.focused_id.is_some() {
+            self.root
+                .trigger_event_with_bubble(&event_type, ctx.clone());
+        }
+        if let Some(listeners) = self.action_listeners.get(action) {
+            for (_, listener) in listeners {
+                listener(ctx.clone());
+            }
+        }
+    }
+
+    /// Move focus for Tab/BackTab, and arrow keys when spatial navigation is on.
+    fn navigate_focus(&mut self, key: KeyEvent) {
+        let scope = self.focus_scope();
+        let current = self.focused_id.as_deref();
+        let next = match key.code {
+            KeyCode::Tab if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                scope.next_focus(current, false)
+            }
+            KeyCode::Tab => scope.next_focus(current, true),
+            KeyCode::BackTab => scope.next_focus(current, false),
+            code if self.spatial_navigation => {
+                let direction = match code {
+                    KeyCode::Up => FocusDirection::Up,
+                    KeyCode::Down => FocusDirection::Down,
+                    KeyCode::Left => FocusDirection::Left,
+                    KeyCode::Right => FocusDirection::Right,
+                    _ => return,
+                };
+                match current {
+                    Some(id) => scope.spatial_focus(id, direction),
+                    None => scope.next_focus(None, true),
+                }
+            }
+            _ => return,
+        };
+
+        if next.is_some() && next != self.focused_id {
+            self.set_focus(next, None);
+        }
+    }
+
+    /// Handle a mouse event: hover/press tracking, enter/leave, dragging,
+    /// then dispatch to the element under the cursor and click-to-focus.
+    fn handle_mouse_event(&mut self, mouse: MouseEvent) {
+        let release_target = match mouse.kind {
+            MouseEventKind::Down(_) | MouseEventKind::Up(MouseButton::Left) => {
+                self.release_target.take()
+            }
+            _ => None,
+        };
+
+        // Track hovered and pressed nodes for pseudo-state styles
+        match mouse.kind {
+            MouseEventKind::Moved | MouseEventKind::Drag(_) => {
+                let hovered = self.focus_scope().find_node_at(mouse.column, mouse.row);
+                if hovered != self.hovered_id {
+                    let previous = std::mem::replace(&mut self.hovered_id, hovered);
+                    self.fire_enter_leave(previous, mouse);
+                    self.styles_dirty = true;
+                }
+            }
+            MouseEventKind::Down(MouseButton::Left) => {
+                self.pressed_id = self.focus_scope().find_node_at(mouse.column, mouse.row);
+                self.styles_dirty = true;
+            }
+            MouseEventKind::Up(MouseButton::Left) if self.pressed_id.is_some() => {
+                self.pressed_id = None;
+                self.styles_dirty = true;
+            }
+            _ => {}
+        }
+
+        // Drag events go to the node the press started on, wherever the cursor is
+        self.handle_drag(mouse);
+
+        // While a modal is open, mouse input outside of it is ignored
+        if self
+            .focus_scope()
+            .find_node_at(mouse.column, mouse.row)
+            .is_none()
+        {
+            return;
+        }
+
+        // Dispatch to element under mouse
+        let mut default_prevented = self.dispatch_mouse_event(mouse);
+
+        // Default action: releasing over the widget the press started on
+        // completes it; moving off before letting go cancels
+        if mouse.kind == MouseEventKind::Up(MouseButton::Left)
+            && !default_prevented
+            && release_target.is_some()
+            && release_target == self.focus_scope().find_node_at(mouse.column, mouse.row)
+        {
+            self.widget_mouse(mouse);
+        }
+
+        // Clicks: any button press counts towards a double/triple click
+        if let MouseEventKind::Down(button) = mouse.kind {
+            let count = self.count_click(button, (mouse.column, mouse.row));
+
+            // Disabled widgets can't take focus or be clicked
+            let clicked_id = self
+                .focus_scope()
+                .find_widget_at(mouse.column, mouse.row)
+                .filter(|id| !self.is_disabled(id));
+
+            // Trigger click listeners with bubbling (if clicked on a widget)
+            if let Some(ref id) = clicked_id {
+                let event_types: &[EventType] = match (button, count) {
+                    (MouseButton::Left, 2) => &[EventType::Click, EventType::DoubleClick],
+                    (MouseButton::Left, 3) => &[EventType::Click, EventType::TripleClick],
+                    (MouseButton::Left, _) => &[EventType::Click],
+                    (MouseButton::Right, _) => &[EventType::RightClick],
+                    (MouseButton::Middle, _) => &[EventType::MiddleClick],
+                };
+                for event_type in event_types {
+                    let ctx = EventContext {
+                        click_count: count,
+                        ..self.mouse_ctx(event_type.clone(), id.clone(), mouse)
+                    };
+                    self.root.trigger_event_with_bubble(event_type, ctx.clone());
+                    default_prevented |= ctx.is_default_prevented();
+                }
+            }
+
+            // Default action: let the widget react to the press (e.g. a list)
+            if !default_prevented {
+                self.widget_mouse(mouse);
+                if button == MouseButton::Left {
+                    self.release_target = self.focus_scope().find_node_at(mouse.column, mouse.row);
+                }
+            }
+
+            // Default action: left and right clicks move focus
+            if button != MouseButton::Middle && !default_prevented {
+                // Focus the nearest focusable node under the cursor (or blur)
+                let focus_id = self
+                    .focus_scope()
+                    .find_node_at(mouse.column, mouse.row)
+                    .and_then(|id| {
+                        self.root.ancestor_chain(&id).into_iter().find(|id| {
+                            self.in_focus_scope(id)
+                                && self.root.find_child(id).is_some_and(|node| {
+                                    node.style.focusable && !node.style.disabled
+                                })
+                        })
+                    });
+                if focus_id != self.focused_id {
+                    self.set_focus(focus_id, Some((mouse.column, mouse.row)));
+                }
+            }
+        }
+    }
+
+    /// Offer a mouse event to the (enabled) widget under the cursor, then
+    /// dispatch the events it raised. Returns true if the widget handled it.
+    fn widget_mouse(&mut self, mouse: MouseEvent) -> bool {
+        let Some((id, position)) = self.focus_scope().hit_test(mouse.column, mouse.row) else {
+            return false;
+        };
+        if self.is_disabled(&id) {
+            return false;
+        }
+        let Some(node) = self.root.find_child_mut(&id) else {
+            return false;
+        };
+        let (area, style) = (node.area, node.style.clone());
+        let Some(widget) = node.widget.as_mut() else {
+            return false;
+        };
+        let local = MouseEvent {
+            column: position.x,
+            row: position.y,
+            ..mouse
+        };
+        let handled = widget.handle_mouse(local, area, &style);
+        self.dispatch_widget_events(&id);
+        handled
+    }
+
+    /// Count a press towards a multi-click. Returns 1 for a single click, 2 for
+    /// a double click and 3 for a triple click; a fourth click starts over.
+    fn count_click(&mut self, button: MouseButton, position: (u16, u16)) -> u8 {
+        let now = Instant::now();
+        let count = match self.last_click {
+            Some(last)
+                if last.button == button
+                    && last.count < 3
+                    && now.duration_since(last.at) <= self.multi_click_interval
+                    && last.position.0.abs_diff(position.0) <= self.multi_click_distance
+                    && last.position.1.abs_diff(position.1) <= self.multi_click_distance =>
+            {
+                last.count + 1
+            }
+            _ => 1,
+        };
+        self.last_click = Some(LastClick {
+            button,
+            position,
+            at: now,
+            count,
+        });
+        count
+    }
+
+    /// Fire MouseLeave on nodes the cursor left (deepest first) and
+    /// MouseEnter on nodes it entered (outermost first). Neither bubbles.
+    fn fire_enter_leave(&self, previous: Option<String>, mouse: MouseEvent) {
+        let chain = |id: &Option<String>| {
+            id.as_deref()
+                .map(|id| self.root.ancestor_chain(id))
+                .unwrap_or_default()
+        };
+        let old_chain = chain(&previous);
+        let new_chain = chain(&self.hovered_id);
+
+        for id in old_chain.iter().filter(|id| !new_chain.contains(id)) {
+            self.fire_at(self.mouse_ctx(EventType::MouseLeave, id.clone(), mouse));
+        }
+        for id in new_chain.iter().rev().filter(|id| !old_chain.contains(id)) {
+            self.fire_at(self.mouse_ctx(EventType::MouseEnter, id.clone(), mouse));
+        }
+    }
+
+    /// Call the target's own listeners only, without capture or bubbling.
+    fn fire_at(&self, ctx: EventContext) {
+        if let Some(node) = self.root.find_child(&ctx.target_id) {
+            node.trigger_capture(&ctx.event_type, ctx.clone());
+            node.trigger_event(&ctx.event_type, ctx.clone());
+        }
+    }
+
+    /// Track a press and turn button-held movement into DragStart/Drag/DragEnd.
+    fn handle_drag(&mut self, mouse: MouseEvent) {
+        let position = (mouse.column, mouse.row);
+        match mouse.kind {
+            MouseEventKind::Down(button) => {
+                self.drag = self
+                    .focus_scope()
+                    .find_node_at(mouse.column, mouse.row)
+                    .filter(|id| !self.is_disabled(id))
+                    .map(|id| DragState {
+                        id,
+                        button,
+                        origin: position,
+                        last: position,
+                        started: false,
+                    });
+            }
+            MouseEventKind::Drag(button) => {
+                let Some(drag) = self.drag.as_mut().filter(|drag| drag.button == button) else {
+                    return;
+                };
+                let first = !drag.started;
+                drag.started = true;
+                let delta = (
+                    position.0 as i32 - drag.last.0 as i32,
+                    position.1 as i32 - drag.last.1 as i32,
+                );
+                drag.last = position;
+                let (id, origin) = (drag.id.clone(), drag.origin);
+
+                if first {
+                    let ctx = EventContext {
+                        drag_delta: Some((0, 0)),
+                        drag_origin: Some(origin),
+                        ..self.mouse_ctx(EventType::DragStart, id.clone(), mouse)
+                    };
+                    self.root
+                        .trigger_event_with_bubble(&EventType::DragStart, ctx);
+                }
+                let ctx = EventContext {
+                    drag_delta: Some(delta),
+                    drag_origin: Some(origin),
+                    ..self.mouse_ctx(EventType::Drag, id, mouse)
+                };
+                self.root.trigger_event_with_bubble(&EventType::Drag, ctx);
+            }
+            MouseEventKind::Up(button) => {
+                let Some(drag) = self.drag.take() else {
+                    return;
+                };
+                if drag.started && drag.button == button {
+                    // Total movement since the press
+                    let delta = (
+                        position.0 as i32 - drag.origin.0 as i32,
+                        position.1 as i32 - drag.origin.1 as i32,
+                    );
+                    let ctx = EventContext {
+                        drag_delta: Some(delta),
+                        drag_origin: Some(drag.origin),
+                        ..self.mouse_ctx(EventType::DragEnd, drag.id, mouse)
+                    };
+                    self.root
+                        .trigger_event_with_bubble(&EventType::DragEnd, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Context for an event on `target_id` whose `ui()` feeds this loop.
+    fn event_ctx(&self, event_type: EventType, target_id: impl Into<String>) -> EventContext {
+        EventContext {
+            ui: self.commands.clone(),
+            ..EventContext::new(event_type, target_id)
+        }
+    }
+
+    fn key_ctx(&self, key: KeyEvent, target_id: impl Into<String>) -> EventContext {
+        EventContext {
+            ui: self.commands.clone(),
+            ..EventContext::key(key, target_id)
+        }
+    }
+
+    /// Context for a mouse event on `target_id`.
+    fn mouse_ctx(
+        &self,
+        event_type: EventType,
+        target_id: String,
+        mouse: MouseEvent,
+    ) -> EventContext {
+        let button = match mouse.kind {
+            MouseEventKind::Down(button)
+            | MouseEventKind::Up(button)
+            | MouseEventKind::Drag(button) => Some(button),
+            _ => None,
+        };
+        EventContext {
+            mouse_x: Some(mouse.column),
+            mouse_y: Some(mouse.row),
+            button,
+            modifiers: mouse.modifiers,
+            ..self.event_ctx(event_type, target_id)
+        }
+    }
+
+    fn is_disabled(&self, id: &str) -> bool {
+        self.root
+            .find_child(id)
+            .is_some_and(|node| node.style.disabled)
+    }
+
+    /// Recompute every node's style from inline styles, states and the stylesheet.
+    fn compute_styles(&mut self) {
+        let hovered = self
+            .hovered_id
+            .as_deref()
+            .map(|id| self.root.ancestor_chain(id))
+            .unwrap_or_default();
+        let active = self
+            .pressed_id
+            .as_deref()
+            .map(|id| self.root.ancestor_chain(id))
+            .unwrap_or_default();
+        let interaction = InteractionState {
+            focused_id: self.focused_id.as_deref(),
+            hovered: &hovered,
+            active: &active,
+        };
+        self.root
+            .compute_styles(&self.stylesheet, &interaction, &mut Vec::new());
+        self.styles_dirty = false;
+
+        // Keep focus inside the topmost modal, restoring it when modals close
+        self.sync_modals();
+
+        // A node that was hidden or disabled can't keep focus
+        if let Some(id) = self.focused_id.clone()
+            && (!self.root.is_node_shown(&id) || self.is_disabled(&id))
+        {
+            self.set_focus(None, None);
+        }
+
+        // Focus changes affect :focus styles
+        if self.styles_dirty {
+            self.compute_styles();
+        }
+    }
+
+    /// Match `modal_stack` against the modal nodes currently shown.
+    ///
+    /// A newly opened modal remembers the focused node and pulls focus inside;
+    /// a closed one hands its remembered focus back.
+    fn sync_modals(&mut self) {
+        let open = self.root.modal_ids();
+
+        // Close modals that were removed, hidden or lost the modal flag
+        while let Some(index) = self
+            .modal_stack
+            .iter()
+            .position(|modal| !open.contains(&modal.id))
+        {
+            let closed = self.modal_stack.remove(index);
+            match self.modal_stack.get_mut(index) {
+                // A modal opened on top of the closed one inherits its restore target
+                Some(above) => above.previous_focus = closed.previous_focus,
+                None => {
+                    let restore = closed
+                        .previous_focus
+                        .filter(|id| self.root.is_node_shown(id) && self.in_focus_scope(id));
+                    if restore != self.focused_id {
+                        self.set_focus(restore, None);
                     }
                 }
             }
+        }
 
-            // TODO: add user-configurable FPS limit here
-            tokio::time::sleep(tokio::time::Duration::from_millis(0)).await;
+        // Open new modals and move focus into them
+        for id in open {
+            if self.modal_stack.iter().any(|modal| modal.id == id) {
+                continue;
+            }
+            self.modal_stack.push(ModalScope {
+                id,
+                previous_focus: self.focused_id.clone(),
+            });
+            if !self
+                .focused_id
+                .as_deref()
+                .is_some_and(|id| self.in_focus_scope(id))
+            {
+                let first = self.focus_scope().next_focus(None, true);
+                self.set_focus(first, None);
+            }
         }
     }
 
+    /// Root of focus traversal and hit testing: the topmost modal, or the whole tree.
+    fn focus_scope(&self) -> &Node {
+        self.modal_stack
+            .last()
+            .and_then(|modal| self.root.find_child(&modal.id))
+            .unwrap_or(&self.root)
+    }
+
+    /// Whether `id` is inside the current focus scope.
+    fn in_focus_scope(&self, id: &str) -> bool {
+        match self.modal_stack.last() {
+            Some(modal) => self.root.ancestor_chain(id).contains(&modal.id),
+            None => true,
+        }
+    }
+
+    /// Move focus to `id` (or clear it), firing Blur and Focus events.
+    fn set_focus(&mut self, id: Option<String>, mouse: Option<(u16, u16)>) {
+        // Blur old
+        if let Some(old_id) = self.focused_id.take() {
+            let ctx = self.event_ctx(EventType::Blur, old_id);
+            self.root.trigger_event_with_bubble(&EventType::Blur, ctx);
+        }
+
+        // Focus new
+        if let Some(ref id) = id {
+            self.focused_id = Some(id.clone());
+            let ctx = EventContext {
+                mouse_x: mouse.map(|(x, _)| x),
+                mouse_y: mouse.map(|(_, y)| y),
+                ..self.event_ctx(EventType::Focus, id.clone())
+            };
+            self.root.trigger_event_with_bubble(&EventType::Focus, ctx);
+        }
+
+        *self.focus_mirror.lock() = self.focused_id.clone();
+        self.styles_dirty = true;
+    }
+
     /// Trigger global listeners for an event type.
-    fn trigger_global_listeners(&self, event_type: &EventType, key: KeyEvent) {
+    fn trigger_global_listeners(&self, event_type: &EventType, ctx: &EventContext) {
         if let Some(listeners) = self.global_listeners.get(event_type) {
+            let mut ctx = ctx.clone();
+            ctx.current_target_id = String::from("global");
             for (_, listener) in listeners {
-                let ctx = EventContext {
-                    event_type: event_type.clone(),
-                    target_id: String::from("global"),
-                    current_target_id: String::from("global"),
-                    mouse_x: None,
-                    mouse_y: None,
-                    scroll_delta: None,
-                    key_code: Some(key.code),
-                    propagation_stopped: false,
-                };
-                listener(ctx);
+                if ctx.is_propagation_stopped() {
+                    break;
+                }
+                listener(ctx.clone());
             }
         }
     }
 
     /// Dispatch mouse events to the element under the cursor.
-    fn dispatch_mouse_event(&mut self, mouse: crossterm::event::MouseEvent) {
-        // Convert to EventType
+    /// Returns true if a listener prevented the default action.
+    fn dispatch_mouse_event(&mut self, mouse: MouseEvent) -> bool {
+        // Convert to EventType (drags are handled by `handle_drag`)
         let event_type = match mouse.kind {
-            MouseEventKind::Down(_) => EventType::Click,
-            MouseEventKind::Up(_) => return,
-            MouseEventKind::Drag(_) => return,
+            MouseEventKind::Down(_) => EventType::MouseDown,
+            MouseEventKind::Up(_) => EventType::MouseUp,
+            MouseEventKind::Drag(_) => return false,
             MouseEventKind::Moved => EventType::Hover,
             MouseEventKind::ScrollUp => EventType::ScrollUp,
             MouseEventKind::ScrollDown => EventType::ScrollDown,
@@ -199,26 +1234,23 @@ impl RenderLoop {
             MouseEventKind::ScrollRight => EventType::ScrollRight,
         };
 
-        // For scroll events: find the scrollview container at the mouse position
-        // For other events: find the deepest widget at the mouse position
-        let target_id = if matches!(
+        let is_scroll = matches!(
             mouse.kind,
             MouseEventKind::ScrollUp
                 | MouseEventKind::ScrollDown
                 | MouseEventKind::ScrollLeft
                 | MouseEventKind::ScrollRight
-        ) {
-            // Find scrollview container
-            match self.root.find_scrollview_at(mouse.column, mouse.row) {
-                Some(id) => id,
-                None => return,
-            }
-        } else {
-            // Find deepest widget
-            match self.root.find_widget_at(mouse.column, mouse.row) {
-                Some(id) => id,
-                None => return,
-            }
+        );
+
+        // Find the deepest node; disabled nodes get no button events, but the
+        // wheel still bubbles through them to a ScrollView
+        let target_id = match self
+            .focus_scope()
+            .find_node_at(mouse.column, mouse.row)
+            .filter(|id| event_type == EventType::Hover || is_scroll || !self.is_disabled(id))
+        {
+            Some(id) => id,
+            None => return false,
         };
 
         // Check if Shift is pressed (for horizontal scroll)
@@ -226,28 +1258,20 @@ impl RenderLoop {
 
         // Build event context
         let ctx = EventContext {
-            event_type: event_type.clone(),
-            target_id: target_id.clone(),
-            current_target_id: target_id.clone(),
-            mouse_x: Some(mouse.column),
-            mouse_y: Some(mouse.row),
             scroll_delta: match mouse.kind {
                 MouseEventKind::ScrollUp => Some(1),
                 MouseEventKind::ScrollDown => Some(-1),
                 _ => None,
             },
-            key_code: None,
-            propagation_stopped: false,
+            ..self.mouse_ctx(event_type.clone(), target_id.clone(), mouse)
         };
 
-        // For scroll events, handle scroll state first, then bubble
-        if matches!(
-            mouse.kind,
-            MouseEventKind::ScrollUp
-                | MouseEventKind::ScrollDown
-                | MouseEventKind::ScrollLeft
-                | MouseEventKind::ScrollRight
-        ) {
+        // Trigger event with bubbling
+        self.root
+            .trigger_event_with_bubble(&event_type, ctx.clone());
+
+        // For scroll events, scroll unless a listener prevented it
+        if !ctx.is_default_prevented() && is_scroll {
             let (delta_x, delta_y) = match mouse.kind {
                 MouseEventKind::ScrollLeft => (-1, 0),
                 MouseEventKind::ScrollRight => (1, 0),
@@ -258,14 +1282,19 @@ impl RenderLoop {
                 _ => (0, 0),
             };
 
-            // Handle scroll on the target node
-            if let Some(node) = self.root.find_child_mut(&target_id) {
+            // Widgets that scroll their own rows (e.g. a List) take the wheel
+            // before the ScrollView around them, unless they can't move further
+            if !self.widget_mouse(mouse)
+                && let Some(id) = self
+                    .focus_scope()
+                    .find_scrollview_at(mouse.column, mouse.row)
+                && let Some(node) = self.root.find_child_mut(&id)
+            {
                 node.handle_scroll(delta_x, delta_y);
             }
         }
 
-        // Trigger event with bubbling
-        self.root.trigger_event_with_bubble(&event_type, ctx);
+        ctx.is_default_prevented()
     }
 
     /// Handle a UI message from the framework.
@@ -274,38 +1303,75 @@ impl RenderLoop {
             UiMessage::AddWidget {
                 parent_id,
                 id,
+                kind,
                 widget,
                 style,
             } => {
-                self.root.add_widget_box(&parent_id, id, widget, style);
+                self.root
+                    .add_widget_box(&parent_id, id, kind, widget, *style);
+                self.styles_dirty = true;
             }
             UiMessage::AddContainer {
                 parent_id,
                 id,
                 style,
             } => {
-                self.root.add_container(&parent_id, id, style);
+                self.root.add_container(&parent_id, id, *style);
+                self.styles_dirty = true;
             }
             UiMessage::RemoveWidget(id) => {
                 self.root.remove_child(&id);
+                self.styles_dirty = true;
             }
             UiMessage::UpdateWidget { id, widget } => {
                 self.root.update_widget_box(&id, widget);
             }
-            UiMessage::UpdateStyle { id, style } => {
-                self.root.update_style(&id, style);
+            UiMessage::UpdateStyle { id, update } => {
+                self.root.update_style(&id, update);
+                self.styles_dirty = true;
+            }
+            UiMessage::SetClass { id, class, enable } => {
+                self.root.set_class(&id, &class, enable);
+                self.styles_dirty = true;
+            }
+            UiMessage::SetDisplayed { id, shown } => {
+                self.root.set_displayed(&id, shown);
+                self.styles_dirty = true;
+            }
+            UiMessage::SetDisabled { id, disabled } => {
+                if let Some(node) = self.root.find_child_mut(&id) {
+                    node.inline_style.disabled = disabled;
+                }
+                self.styles_dirty = true;
+            }
+            UiMessage::SetStylesheet(stylesheet) => {
+                self.stylesheet = stylesheet;
+                self.styles_dirty = true;
             }
             UiMessage::AddEventListener {
                 target_id,
                 event_type,
                 listener,
                 listener_id,
+                capture,
             } => {
-                self.root
-                    .add_event_listener(&target_id, event_type, listener, listener_id);
+                self.root.add_event_listener(
+                    &target_id,
+                    event_type,
+                    listener,
+                    listener_id,
+                    capture,
+                );
             }
             UiMessage::RemoveEventListener { listener_id } => {
                 self.root.remove_event_listener(listener_id);
+                for listeners in self.global_listeners.values_mut() {
+                    listeners.retain(|(id, _)| *id != listener_id);
+                }
+                self.key_bindings.retain(|(id, _, _)| *id != listener_id);
+                for listeners in self.action_listeners.values_mut() {
+                    listeners.retain(|(id, _)| *id != listener_id);
+                }
             }
             UiMessage::AddGlobalListener {
                 event_type,
@@ -314,9 +1380,100 @@ impl RenderLoop {
             } => {
                 self.global_listeners
                     .entry(event_type)
-                    .or_insert_with(Vec::new)
+                    .or_default()
                     .push((listener_id, listener));
             }
+            UiMessage::AddKeyBinding {
+                keys,
+                listener,
+                listener_id,
+            } => {
+                self.key_bindings.push((listener_id, keys, listener));
+            }
+            UiMessage::SetMultiClick { interval, distance } => {
+                self.multi_click_interval = interval;
+                self.multi_click_distance = distance;
+            }
+            UiMessage::SetChordTimeout(timeout) => {
+                self.chord_timeout = timeout;
+            }
+            UiMessage::SetKeymap(keymap) => {
+                self.keymap = keymap;
+                self.pending_keys.clear();
+                self.pending_since = None;
+            }
+            UiMessage::AddActionListener {
+                action,
+                listener,
+                listener_id,
+            } => {
+                self.action_listeners
+                    .entry(action)
+                    .or_default()
+                    .push((listener_id, listener));
+            }
+            UiMessage::AddTimer {
+                timer_id,
+                kind,
+                callback,
+            } => {
+                let now = Instant::now();
+                match kind {
+                    TimerKind::Timeout(delay) => self.timers.push(Timer {
+                        id: timer_id,
+                        due: now + delay,
+                        interval: None,
+                        callback,
+                    }),
+                    TimerKind::Interval(period) => {
+                        // Shorter periods would only keep the loop spinning
+                        let period = period.max(FRAME_INTERVAL);
+                        self.timers.push(Timer {
+                            id: timer_id,
+                            due: now + period,
+                            interval: Some(period),
+                            callback,
+                        });
+                    }
+                    TimerKind::AnimationFrame => self.frame_callbacks.push((timer_id, callback)),
+                }
+            }
+            UiMessage::ClearTimer(timer_id) => {
+                self.timers.retain(|timer| timer.id != timer_id);
+                self.frame_callbacks.retain(|(id, _)| *id != timer_id);
+                self.animations.retain(|animation| animation.id != timer_id);
+            }
+            UiMessage::Animate {
+                id,
+                timer_id,
+                animation,
+            } => self.start_animation(id, timer_id, animation),
+            UiMessage::Dispatch { target_id, event } => {
+                self.dispatch_custom(target_id, event);
+            }
+            UiMessage::TriggerAction(action) => {
+                self.dispatch_action(&action, None);
+            }
+            UiMessage::SetTheme(theme) => {
+                self.theme = theme;
+            }
+            UiMessage::Focus(id) => {
+                // Only shown, enabled nodes inside the focus scope can take focus
+                let id = id.filter(|id| {
+                    self.in_focus_scope(id)
+                        && self.root.is_node_shown(id)
+                        && self
+                            .root
+                            .find_child(id)
+                            .is_some_and(|node| !node.style.disabled)
+                });
+                if id != self.focused_id {
+                    self.set_focus(id, None);
+                }
+            }
+            UiMessage::SetSpatialNavigation(enabled) => {
+                self.spatial_navigation = enabled;
+            }
             UiMessage::ToggleMouseCapture => {
                 self.mouse_capture_enabled = !self.mouse_capture_enabled;
                 if self.mouse_capture_enabled {
@@ -327,12 +1484,387 @@ impl RenderLoop {
             }
             UiMessage::WidgetMessage { id, message } => {
                 // Widget-specific message: let the widget handle it
-                if let Some(node) = self.root.find_child_mut(&id) {
-                    if let Some(widget) = &mut node.widget {
-                        message.apply(&mut **widget);
-                    }
+                if let Some(node) = self.root.find_child_mut(&id)
+                    && let Some(widget) = &mut node.widget
+                {
+                    message.apply(&mut **widget);
                 }
+                // e.g. a Table reporting selected rows that moved
+                self.dispatch_widget_events(&id);
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::FlexDirection;
+    use crate::widget::{Button, Input, Text, Widget};
+
+    #[test]
+    fn update_style_during_animation_keeps_both_changes() {
+        let mut ui = RenderLoop::new(Arc::default());
+        ui.handle_ui_msg(UiMessage::AddContainer {
+            parent_id: "root".to_string(),
+            id: "box".to_string(),
+            style: Box::new(Style::new().size(10, 3)),
+        });
+        ui.handle_ui_msg(UiMessage::Animate {
+            id: "box".to_string(),
+            timer_id: TimerId::new(),
+            animation: Animation::new(|style| style.width = 20, Duration::from_millis(100))
+                .easing(Easing::Linear),
+        });
+        let start = ui.animations[0].start;
+        let inline = |ui: &RenderLoop| ui.root.find_child("box").unwrap().inline_style.clone();
+
+        ui.step_animations(start + Duration::from_millis(50));
+        assert_eq!(inline(&ui).width, 15);
+
+        ui.handle_ui_msg(UiMessage::UpdateStyle {
+            id: "box".to_string(),
+            update: Box::new(|style| style.title = Some("Title".to_string())),
+        });
+        ui.step_animations(start + Duration::from_millis(80));
+        assert_eq!(inline(&ui).width, 18);
+        assert_eq!(inline(&ui).title.as_deref(), Some("Title"));
+
+        // The finished animation doesn't revert the update either
+        ui.step_animations(start + Duration::from_millis(150));
+        assert!(ui.animations.is_empty());
+        assert_eq!(inline(&ui).width, 20);
+        assert_eq!(inline(&ui).title.as_deref(), Some("Title"));
+
+        ui.handle_ui_msg(UiMessage::UpdateStyle {
+            id: "box".to_string(),
+            update: Box::new(|style| style.gap = 2),
+        });
+        assert_eq!(inline(&ui).width, 20);
+    }
+
+    #[test]
+    fn stylesheet_rules_beat_container_creation_styles() {
+        let mut ui = RenderLoop::new(Arc::default());
+        ui.handle_ui_msg(UiMessage::AddContainer {
+            parent_id: "root".to_string(),
+            id: "box".to_string(),
+            style: Box::new(Style::new().row().gap(1)),
+        });
+        let stylesheet = Stylesheet::parse("#box { gap: 2; flex-direction: column; }").unwrap();
+        ui.handle_ui_msg(UiMessage::SetStylesheet(stylesheet));
+        ui.compute_styles();
+        let computed = |ui: &RenderLoop| ui.root.find_child("box").unwrap().style.clone();
+        assert_eq!(computed(&ui).gap, 2);
+        assert_eq!(computed(&ui).flex_direction, FlexDirection::Column);
+
+        // Later inline changes still win
+        ui.handle_ui_msg(UiMessage::UpdateStyle {
+            id: "box".to_string(),
+            update: Box::new(|style| style.gap = 3),
+        });
+        ui.compute_styles();
+        assert_eq!(computed(&ui).gap, 3);
+    }
+
+    fn press(ui: &mut RenderLoop, text: &str) {
+        for binding in KeySequence::parse(text).unwrap().keys() {
+            ui.handle_key_event(KeyEvent::new(binding.code, binding.modifiers));
+        }
+    }
+
+    fn counter(ui: &mut RenderLoop, keys: &str) -> Arc<Mutex<usize>> {
+        let count = Arc::new(Mutex::new(0));
+        let hits = Arc::clone(&count);
+        ui.handle_ui_msg(UiMessage::AddKeyBinding {
+            keys: KeySequence::parse(keys).unwrap(),
+            listener: Arc::new(move |_| *hits.lock() += 1),
+            listener_id: ListenerId::new(),
+        });
+        count
+    }
+
+    #[test]
+    fn broken_sequence_replays_its_keys() {
+        let mut ui = RenderLoop::new(Arc::default());
+        let saved = counter(&mut ui, "ctrl+x ctrl+s");
+        let pressed = Arc::new(Mutex::new(Vec::new()));
+        for key in ["ctrl+x", "a"] {
+            let pressed = Arc::clone(&pressed);
+            ui.handle_ui_msg(UiMessage::AddGlobalListener {
+                event_type: EventType::KeyPress(KeyBinding::parse(key).unwrap()),
+                listener: Arc::new(move |_| pressed.lock().push(key)),
+                listener_id: ListenerId::new(),
+            });
+        }
+
+        press(&mut ui, "ctrl+x");
+        assert!(pressed.lock().is_empty());
+        press(&mut ui, "a");
+        assert_eq!(*pressed.lock(), ["ctrl+x", "a"]);
+
+        press(&mut ui, "ctrl+x ctrl+s");
+        assert_eq!(*saved.lock(), 1);
+        assert_eq!(pressed.lock().len(), 2);
+    }
+
+    #[test]
+    fn text_fields_keep_plain_characters() {
+        let mut ui = RenderLoop::new(Arc::default());
+        let widget = Input::new();
+        let style = widget.node_style_hint().unwrap_or_default();
+        ui.handle_ui_msg(UiMessage::AddWidget {
+            parent_id: "root".to_string(),
+            id: "name".to_string(),
+            kind: WidgetKind::Input,
+            widget: Box::new(widget),
+            style: Box::new(style),
+        });
+        ui.handle_ui_msg(UiMessage::Focus(Some("name".to_string())));
+        let jumped = counter(&mut ui, "g g");
+
+        press(&mut ui, "g g");
+        assert_eq!(*jumped.lock(), 0);
+        let node = ui.root.find_child_mut("name").unwrap();
+        let input = node.widget.as_mut().unwrap().as_any_mut();
+        assert_eq!(input.downcast_mut::<Input>().unwrap().value(), "gg");
+    }
+
+    #[test]
+    fn context_bindings_override_global_ones() {
+        let mut ui = RenderLoop::new(Arc::default());
+        ui.handle_ui_msg(UiMessage::AddContainer {
+            parent_id: "root".to_string(),
+            id: "form".to_string(),
+            style: Box::default(),
+        });
+        for parent in ["root", "form"] {
+            let widget = Input::new();
+            let style = widget.node_style_hint().unwrap_or_default();
+            ui.handle_ui_msg(UiMessage::AddWidget {
+                parent_id: parent.to_string(),
+                id: format!("{}-input", parent),
+                kind: WidgetKind::Input,
+                widget: Box::new(widget),
+                style: Box::new(style),
+            });
+        }
+        let keymap = Keymap::from_toml(
+            r#"
+            [global]
+            save = "ctrl+s"
+            [container.form]
+            submit = "ctrl+s"
+            "#,
+        )
+        .unwrap();
+        ui.handle_ui_msg(UiMessage::SetKeymap(keymap));
+        let actions: Log = Arc::default();
+        for action in ["save", "submit"] {
+            let actions = Arc::clone(&actions);
+            ui.handle_ui_msg(UiMessage::AddActionListener {
+                action: action.to_string(),
+                listener: Arc::new(move |_| actions.lock().push(action)),
+                listener_id: ListenerId::new(),
+            });
+        }
+
+        ui.handle_ui_msg(UiMessage::Focus(Some("form-input".to_string())));
+        press(&mut ui, "ctrl+s");
+        ui.handle_ui_msg(UiMessage::Focus(Some("root-input".to_string())));
+        press(&mut ui, "ctrl+s");
+        assert_eq!(*actions.lock(), ["submit", "save"]);
+    }
+
+    #[tokio::test]
+    async fn commands_from_a_spawned_task_wake_the_loop() {
+        let mut ui = RenderLoop::new(Arc::default());
+        ui.handle_ui_msg(UiMessage::AddWidget {
+            parent_id: "root".to_string(),
+            id: "status".to_string(),
+            kind: WidgetKind::Text,
+            widget: Box::new(Text::new("Loading")),
+            style: Box::default(),
+        });
+        let commands = ui.commands.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            commands.set_content("status", "Loaded");
+        });
+
+        // No UI message, terminal event or timer arrives
+        let (_ui_tx, mut ui_rx) = mpsc::channel(1);
+        let (event_tx, _event_rx) = mpsc::channel(1);
+        let mut terminal_events = tokio_stream::pending();
+        let wait = ui.wait(&mut ui_rx, &mut terminal_events, &event_tx);
+        let woke = tokio::time::timeout(Duration::from_secs(5), wait).await;
+        assert!(matches!(woke, Ok(true)));
+
+        ui.apply_commands();
+        let node = ui.root.find_child_mut("status").unwrap();
+        let text = node.widget.as_mut().unwrap().as_any_mut();
+        assert_eq!(text.downcast_mut::<Text>().unwrap().content(), "Loaded");
+    }
+
+    type Log = Arc<Mutex<Vec<&'static str>>>;
+
+    fn add_timer(ui: &mut RenderLoop, kind: TimerKind, log: &Log, name: &'static str) -> TimerId {
+        let timer_id = TimerId::new();
+        let log = Arc::clone(log);
+        ui.handle_ui_msg(UiMessage::AddTimer {
+            timer_id,
+            kind,
+            callback: Box::new(move |_| log.lock().push(name)),
+        });
+        timer_id
+    }
+
+    #[test]
+    fn timeouts_fire_once_in_due_order() {
+        let mut ui = RenderLoop::new(Arc::default());
+        let log = Log::default();
+        let start = Instant::now();
+        add_timer(
+            &mut ui,
+            TimerKind::Timeout(Duration::from_millis(30)),
+            &log,
+            "b",
+        );
+        add_timer(
+            &mut ui,
+            TimerKind::Timeout(Duration::from_millis(10)),
+            &log,
+            "a",
+        );
+        add_timer(
+            &mut ui,
+            TimerKind::Timeout(Duration::from_millis(50)),
+            &log,
+            "c",
+        );
+
+        ui.run_timers(start + Duration::from_millis(40));
+        assert_eq!(*log.lock(), ["a", "b"]);
+        ui.run_timers(start + Duration::from_millis(60));
+        ui.run_timers(start + Duration::from_millis(100));
+        assert_eq!(*log.lock(), ["a", "b", "c"]);
+        assert!(ui.timers.is_empty());
+    }
+
+    #[test]
+    fn intervals_reschedule_without_bursts() {
+        let mut ui = RenderLoop::new(Arc::default());
+        let log = Log::default();
+        let period = Duration::from_millis(100);
+        add_timer(&mut ui, TimerKind::Interval(period), &log, "tick");
+        let due = ui.timers[0].due;
+
+        ui.run_timers(due - Duration::from_millis(1));
+        assert!(log.lock().is_empty());
+        ui.run_timers(due);
+        assert_eq!(log.lock().len(), 1);
+        assert_eq!(ui.timers[0].due, due + period);
+
+        // Missed ticks are skipped, not run in a burst
+        let late = due + Duration::from_millis(350);
+        ui.run_timers(late);
+        assert_eq!(log.lock().len(), 2);
+        assert_eq!(ui.timers[0].due, late + period);
+    }
+
+    #[test]
+    fn zero_interval_waits_a_frame() {
+        let mut ui = RenderLoop::new(Arc::default());
+        let log = Log::default();
+        add_timer(&mut ui, TimerKind::Interval(Duration::ZERO), &log, "tick");
+        assert_eq!(ui.timers[0].interval, Some(FRAME_INTERVAL));
+
+        let now = ui.timers[0].due;
+        ui.run_timers(now);
+        assert_eq!(log.lock().len(), 1);
+        assert!(ui.next_deadline().unwrap() > now);
+    }
+
+    #[test]
+    fn clear_timer_cancels_timers() {
+        let mut ui = RenderLoop::new(Arc::default());
+        let log = Log::default();
+        let timeout = add_timer(&mut ui, TimerKind::Timeout(Duration::ZERO), &log, "timeout");
+        ui.handle_ui_msg(UiMessage::ClearTimer(timeout));
+
+        // An interval clearing itself from its own callback
+        let count = Arc::new(Mutex::new(0));
+        let ticks = Arc::clone(&count);
+        ui.handle_ui_msg(UiMessage::AddTimer {
+            timer_id: TimerId::new(),
+            kind: TimerKind::Interval(Duration::from_millis(100)),
+            callback: Box::new(move |ctx| {
+                *ticks.lock() += 1;
+                ctx.ui().clear_timer(ctx.timer_id);
+            }),
+        });
+
+        let later = Instant::now() + Duration::from_secs(1);
+        ui.run_timers(later);
+        ui.apply_commands();
+        ui.run_timers(later + Duration::from_secs(1));
+        assert!(log.lock().is_empty());
+        assert_eq!(*count.lock(), 1);
+        assert!(ui.timers.is_empty());
+    }
+
+    fn mouse(ui: &mut RenderLoop, kind: MouseEventKind, column: u16, row: u16) {
+        ui.handle_mouse_event(MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        });
+    }
+
+    #[test]
+    fn buttons_press_on_release_over_them() {
+        let mut ui = RenderLoop::new(Arc::default());
+        let widget = Button::new("OK");
+        let style = widget.node_style_hint().unwrap_or_default();
+        ui.handle_ui_msg(UiMessage::AddWidget {
+            parent_id: "root".to_string(),
+            id: "ok".to_string(),
+            kind: WidgetKind::Button,
+            widget: Box::new(widget),
+            style: Box::new(style),
+        });
+        let presses = Arc::new(Mutex::new(0));
+        let count = Arc::clone(&presses);
+        ui.handle_ui_msg(UiMessage::AddEventListener {
+            target_id: "ok".to_string(),
+            event_type: EventType::Press,
+            listener: Arc::new(move |_| *count.lock() += 1),
+            listener_id: ListenerId::new(),
+            capture: false,
+        });
+        ui.compute_styles();
+        ui.root.layout(ratatui::layout::Rect::new(0, 0, 20, 5));
+        ui.root.find_child_mut("ok").unwrap().area = ratatui::layout::Rect::new(0, 0, 6, 1);
+        let (left_down, left_up) = (
+            MouseEventKind::Down(MouseButton::Left),
+            MouseEventKind::Up(MouseButton::Left),
+        );
+
+        mouse(&mut ui, left_down, 1, 0);
+        assert_eq!(*presses.lock(), 0);
+        mouse(&mut ui, left_up, 2, 0);
+        assert_eq!(*presses.lock(), 1);
+
+        // Moving off before letting go cancels
+        mouse(&mut ui, left_down, 1, 0);
+        mouse(&mut ui, MouseEventKind::Drag(MouseButton::Left), 1, 3);
+        mouse(&mut ui, left_up, 1, 3);
+        assert_eq!(*presses.lock(), 1);
+
+        // A release without a press on the button does nothing
+        mouse(&mut ui, left_down, 1, 3);
+        mouse(&mut ui, left_up, 1, 0);
+        assert_eq!(*presses.lock(), 1);
+    }
+}
@@ -0,0 +1,349 @@
+//! Key bindings and multi-key sequences.
+//!
+//! ```text
+//! "ctrl+s"          -> KeyBinding { Char('s'), CONTROL }
+//! "alt+shift+f1"    -> KeyBinding { F(1), ALT | SHIFT }
+//! "ctrl+x ctrl+s"   -> KeySequence of two bindings
+//! "g g"             -> KeySequence of two bindings
+//! ```
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::fmt;
+use std::str::FromStr;
+
+/// A key together with its modifiers.
+///
+/// Bindings are normalized so that a shifted character matches however the
+/// terminal reports it: `"shift+a"`, `"A"` and a `KeyEvent` of `'A'` with
+/// `SHIFT` are all equal. `"shift+tab"` is the same as `"backtab"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyBinding {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        let mut modifiers = modifiers;
+        let code = match code {
+            KeyCode::Char(c) if modifiers.contains(KeyModifiers::SHIFT) => {
+                modifiers.remove(KeyModifiers::SHIFT);
+                KeyCode::Char(c.to_ascii_uppercase())
+            }
+            KeyCode::Char(c) if c.is_ascii_uppercase() => KeyCode::Char(c),
+            KeyCode::Tab if modifiers.contains(KeyModifiers::SHIFT) => {
+                modifiers.remove(KeyModifiers::SHIFT);
+                KeyCode::BackTab
+            }
+            KeyCode::BackTab => {
+                modifiers.remove(KeyModifiers::SHIFT);
+                KeyCode::BackTab
+            }
+            code => code,
+        };
+        KeyBinding { code, modifiers }
+    }
+
+    /// Parse a binding such as `"ctrl+s"` or `"alt+shift+f1"`.
+    pub fn parse(text: &str) -> Result<Self, KeyParseError> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Err(KeyParseError::new(text, "empty key"));
+        }
+
+        // A trailing '+' is the plus key itself ("ctrl++")
+        let (mods, key) = match text.strip_suffix("++") {
+            Some(mods) => (mods, "+"),
+            None if text == "+" => ("", "+"),
+            None => match text.rsplit_once('+') {
+                Some((mods, key)) => (mods, key),
+                None => ("", text),
+            },
+        };
+
+        let mut modifiers = KeyModifiers::NONE;
+        for name in mods.split('+').filter(|name| !name.is_empty()) {
+            modifiers |= match name.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" | "option" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                "super" | "cmd" | "win" => KeyModifiers::SUPER,
+                "hyper" => KeyModifiers::HYPER,
+                "meta" => KeyModifiers::META,
+                _ => return Err(KeyParseError::new(text, "unknown modifier")),
+            };
+        }
+
+        let code = parse_code(key).ok_or_else(|| KeyParseError::new(text, "unknown key"))?;
+        Ok(KeyBinding::new(code, modifiers))
+    }
+
+    /// Whether the event is a press of this binding.
+    pub fn matches(&self, key: &KeyEvent) -> bool {
+        *self == KeyBinding::from(*key)
+    }
+}
+
+fn parse_code(key: &str) -> Option<KeyCode> {
+    let mut chars = key.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(KeyCode::Char(c));
+    }
+
+    let lower = key.to_ascii_lowercase();
+    if let Some(n) = lower.strip_prefix('f')
+        && let Ok(n) = n.parse::<u8>()
+        && (1..=24).contains(&n)
+    {
+        return Some(KeyCode::F(n));
+    }
+
+    Some(match lower.as_str() {
+        "enter" | "return" => KeyCode::Enter,
+        "esc" | "escape" => KeyCode::Esc,
+        "tab" => KeyCode::Tab,
+        "backtab" => KeyCode::BackTab,
+        "backspace" => KeyCode::Backspace,
+        "delete" | "del" => KeyCode::Delete,
+        "insert" | "ins" => KeyCode::Insert,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" | "pgup" => KeyCode::PageUp,
+        "pagedown" | "pgdn" => KeyCode::PageDown,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "space" => KeyCode::Char(' '),
+        "plus" => KeyCode::Char('+'),
+        "minus" => KeyCode::Char('-'),
+        _ => return None,
+    })
+}
+
+impl From<KeyEvent> for KeyBinding {
+    fn from(key: KeyEvent) -> Self {
+        KeyBinding::new(key.code, key.modifiers)
+    }
+}
+
+impl From<KeyCode> for KeyBinding {
+    fn from(code: KeyCode) -> Self {
+        KeyBinding::new(code, KeyModifiers::NONE)
+    }
+}
+
+impl FromStr for KeyBinding {
+    type Err = KeyParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (modifier, name) in [
+            (KeyModifiers::CONTROL, "ctrl"),
+            (KeyModifiers::ALT, "alt"),
+            (KeyModifiers::SHIFT, "shift"),
+            (KeyModifiers::SUPER, "super"),
+            (KeyModifiers::HYPER, "hyper"),
+            (KeyModifiers::META, "meta"),
+        ] {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{}+", name)?;
+            }
+        }
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "space"),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::F(n) => write!(f, "f{}", n),
+            KeyCode::Enter => write!(f, "enter"),
+            KeyCode::Esc => write!(f, "esc"),
+            KeyCode::Tab => write!(f, "tab"),
+            KeyCode::BackTab => write!(f, "backtab"),
+            KeyCode::Backspace => write!(f, "backspace"),
+            KeyCode::Delete => write!(f, "delete"),
+            KeyCode::Insert => write!(f, "insert"),
+            KeyCode::Home => write!(f, "home"),
+            KeyCode::End => write!(f, "end"),
+            KeyCode::PageUp => write!(f, "pageup"),
+            KeyCode::PageDown => write!(f, "pagedown"),
+            KeyCode::Up => write!(f, "up"),
+            KeyCode::Down => write!(f, "down"),
+            KeyCode::Left => write!(f, "left"),
+            KeyCode::Right => write!(f, "right"),
+            code => write!(f, "{:?}", code),
+        }
+    }
+}
+
+/// A sequence of key bindings pressed one after another, e.g. `"ctrl+x ctrl+s"`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KeySequence(pub Vec<KeyBinding>);
+
+impl KeySequence {
+    /// Parse whitespace-separated bindings, e.g. `"g g"`.
+    pub fn parse(text: &str) -> Result<Self, KeyParseError> {
+        let keys = text
+            .split_whitespace()
+            .map(KeyBinding::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+        if keys.is_empty() {
+            return Err(KeyParseError::new(text, "empty key sequence"));
+        }
+        Ok(KeySequence(keys))
+    }
+
+    pub fn keys(&self) -> &[KeyBinding] {
+        &self.0
+    }
+
+    /// Whether `keys` is this sequence or the start of it.
+    pub fn starts_with(&self, keys: &[KeyBinding]) -> bool {
+        self.0.starts_with(keys)
+    }
+}
+
+impl From<KeyBinding> for KeySequence {
+    fn from(key: KeyBinding) -> Self {
+        KeySequence(vec![key])
+    }
+}
+
+impl From<KeyCode> for KeySequence {
+    fn from(code: KeyCode) -> Self {
+        KeySequence(vec![code.into()])
+    }
+}
+
+impl FromStr for KeySequence {
+    type Err = KeyParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for KeySequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, key) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", key)?;
+        }
+        Ok(())
+    }
+}
+
+/// Error returned when a key binding string can't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyParseError {
+    pub input: String,
+    pub message: String,
+}
+
+impl KeyParseError {
+    fn new(input: &str, message: &str) -> Self {
+        KeyParseError {
+            input: input.to_string(),
+            message: message.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for KeyParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid key '{}': {}", self.input, self.message)
+    }
+}
+
+impl std::error::Error for KeyParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyBinding {
+        KeyBinding::new(code, modifiers)
+    }
+
+    #[test]
+    fn parses_modifiers() {
+        assert_eq!(
+            KeyBinding::parse("ctrl+s").unwrap(),
+            key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        );
+        assert_eq!(
+            KeyBinding::parse("Alt+Shift+F1").unwrap(),
+            key(KeyCode::F(1), KeyModifiers::ALT | KeyModifiers::SHIFT)
+        );
+        assert_eq!(
+            KeyBinding::parse("ctrl++").unwrap(),
+            key(KeyCode::Char('+'), KeyModifiers::CONTROL)
+        );
+        assert_eq!(KeyBinding::parse("+").unwrap(), KeyCode::Char('+').into());
+        assert_eq!(
+            KeyBinding::parse("space").unwrap(),
+            KeyCode::Char(' ').into()
+        );
+    }
+
+    #[test]
+    fn normalizes_shift() {
+        let upper = KeyBinding::parse("A").unwrap();
+        assert_eq!(KeyBinding::parse("shift+a").unwrap(), upper);
+        assert!(upper.matches(&KeyEvent::new(KeyCode::Char('A'), KeyModifiers::SHIFT)));
+        assert_eq!(
+            KeyBinding::parse("shift+tab").unwrap(),
+            KeyBinding::parse("backtab").unwrap()
+        );
+    }
+
+    #[test]
+    fn parses_sequences() {
+        let sequence = KeySequence::parse("ctrl+x  ctrl+s").unwrap();
+        assert_eq!(
+            sequence.keys(),
+            [
+                key(KeyCode::Char('x'), KeyModifiers::CONTROL),
+                key(KeyCode::Char('s'), KeyModifiers::CONTROL),
+            ]
+        );
+        assert!(sequence.starts_with(&sequence.keys()[..1]));
+        assert!(!sequence.starts_with(&[KeyCode::Char('x').into()]));
+        assert_eq!(sequence.to_string(), "ctrl+x ctrl+s");
+        assert_eq!(KeySequence::parse("g g").unwrap().to_string(), "g g");
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for text in ["ctrl+alt+delete", "f12", "shift+up", "B", "space", "ctrl++"] {
+            let binding = KeyBinding::parse(text).unwrap();
+            assert_eq!(KeyBinding::parse(&binding.to_string()).unwrap(), binding);
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_keys() {
+        assert_eq!(KeyBinding::parse("").unwrap_err().message, "empty key");
+        assert_eq!(
+            KeyBinding::parse("hyperctrl+a").unwrap_err().message,
+            "unknown modifier"
+        );
+        assert_eq!(
+            KeyBinding::parse("ctrl+f25").unwrap_err().message,
+            "unknown key"
+        );
+        assert_eq!(
+            KeySequence::parse("   ").unwrap_err().message,
+            "empty key sequence"
+        );
+        assert_eq!(
+            KeySequence::parse("g nope").unwrap_err().to_string(),
+            "invalid key 'nope': unknown key"
+        );
+    }
+}
@@ -0,0 +1,315 @@
+//! Named actions bound to keys, scoped by focus context.
+//!
+//! A [`Keymap`] maps key sequences to action names such as `"save"` or `"quit"`.
+//! Handle actions with `Document::on_action`, or with an `EventType::Action`
+//! listener on the focused element or one of its ancestors.
+//!
+//! Keymaps can be loaded from TOML:
+//!
+//! ```toml
+//! [global]
+//! save = "ctrl+s"
+//! quit = ["ctrl+q", "ctrl+x ctrl+c"]
+//!
+//! # while an input is focused
+//! [kind.input]
+//! clear = "ctrl+u"
+//!
+//! # while focus is inside the node with id "sidebar"
+//! [container.sidebar]
+//! collapse = "ctrl+b"
+//! ```
+
+use crate::key::{KeyParseError, KeySequence};
+use crate::widget::WidgetKind;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Where a binding is active.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum KeyContext {
+    /// Always active.
+    Global,
+    /// Active while the focused node is, or is inside, the node with this id.
+    Container(String),
+    /// Active while a widget of this kind is focused.
+    Kind(WidgetKind),
+}
+
+/// A key sequence bound to an action in a context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeymapEntry {
+    pub context: KeyContext,
+    pub keys: KeySequence,
+    pub action: String,
+}
+
+/// A set of action bindings.
+///
+/// When several contexts bind the same keys, the most specific one wins:
+/// the focused node itself, then its widget kind, then its ancestors from the
+/// nearest outwards, then global bindings.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Keymap {
+    entries: Vec<KeymapEntry>,
+}
+
+impl Keymap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bind keys to an action in a context.
+    pub fn bind(
+        mut self,
+        context: KeyContext,
+        keys: impl Into<KeySequence>,
+        action: impl Into<String>,
+    ) -> Self {
+        self.add(context, keys, action);
+        self
+    }
+
+    /// Bind keys to an action globally.
+    pub fn global(self, keys: impl Into<KeySequence>, action: impl Into<String>) -> Self {
+        self.bind(KeyContext::Global, keys, action)
+    }
+
+    /// Add a binding in place.
+    pub fn add(
+        &mut self,
+        context: KeyContext,
+        keys: impl Into<KeySequence>,
+        action: impl Into<String>,
+    ) {
+        let keys = keys.into();
+        // Rebinding the same keys in the same context replaces the old action
+        self.entries
+            .retain(|entry| entry.context != context || entry.keys != keys);
+        self.entries.push(KeymapEntry {
+            context,
+            keys,
+            action: action.into(),
+        });
+    }
+
+    /// Add all bindings from `other`, overriding bindings for the same keys and context.
+    pub fn extend(&mut self, other: Keymap) {
+        for entry in other.entries {
+            self.add(entry.context, entry.keys, entry.action);
+        }
+    }
+
+    pub fn entries(&self) -> &[KeymapEntry] {
+        &self.entries
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Parse a keymap from TOML (see the module docs for the format).
+    pub fn from_toml(source: &str) -> Result<Self, KeymapError> {
+        let table: toml::Table = source
+            .parse()
+            .map_err(|e: toml::de::Error| KeymapError::Toml(e.to_string()))?;
+
+        let mut keymap = Keymap::new();
+        for (section, value) in &table {
+            match (section.as_str(), value) {
+                ("global", toml::Value::Table(actions)) => {
+                    keymap.add_actions(KeyContext::Global, section, actions)?;
+                }
+                ("kind" | "container", toml::Value::Table(scopes)) => {
+                    for (name, actions) in scopes {
+                        let path = format!("{}.{}", section, name);
+                        let context = if section == "kind" {
+                            let kind = WidgetKind::from_name(name).ok_or_else(|| {
+                                KeymapError::invalid(&path, "unknown widget kind")
+                            })?;
+                            KeyContext::Kind(kind)
+                        } else {
+                            KeyContext::Container(name.clone())
+                        };
+                        let actions = actions
+                            .as_table()
+                            .ok_or_else(|| KeymapError::invalid(&path, "expected a table"))?;
+                        keymap.add_actions(context, &path, actions)?;
+                    }
+                }
+                _ => return Err(KeymapError::invalid(section, "unknown section")),
+            }
+        }
+        Ok(keymap)
+    }
+
+    /// Load a TOML keymap file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, KeymapError> {
+        let source = std::fs::read_to_string(path).map_err(|e| KeymapError::Io(e.to_string()))?;
+        Self::from_toml(&source)
+    }
+
+    fn add_actions(
+        &mut self,
+        context: KeyContext,
+        path: &str,
+        actions: &toml::Table,
+    ) -> Result<(), KeymapError> {
+        for (action, keys) in actions {
+            let path = format!("{}.{}", path, action);
+            let keys: Vec<&str> = match keys {
+                toml::Value::String(keys) => vec![keys.as_str()],
+                toml::Value::Array(list) => list
+                    .iter()
+                    .map(|keys| {
+                        keys.as_str()
+                            .ok_or_else(|| KeymapError::invalid(&path, "expected key strings"))
+                    })
+                    .collect::<Result<_, _>>()?,
+                _ => return Err(KeymapError::invalid(&path, "expected a key string or list")),
+            };
+            for keys in keys {
+                let keys =
+                    KeySequence::parse(keys).map_err(|e| KeymapError::Key(path.clone(), e))?;
+                self.add(context.clone(), keys, action.clone());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Keymap {
+    type Err = KeymapError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_toml(s)
+    }
+}
+
+/// Error returned when a keymap can't be loaded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeymapError {
+    Io(String),
+    Toml(String),
+    /// Invalid entry at a dotted path such as `kind.input.clear`.
+    Invalid {
+        path: String,
+        message: String,
+    },
+    Key(String, KeyParseError),
+}
+
+impl KeymapError {
+    fn invalid(path: &str, message: &str) -> Self {
+        KeymapError::Invalid {
+            path: path.to_string(),
+            message: message.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for KeymapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeymapError::Io(message) => write!(f, "keymap: {}", message),
+            KeymapError::Toml(message) => write!(f, "keymap: {}", message),
+            KeymapError::Invalid { path, message } => write!(f, "keymap '{}': {}", path, message),
+            KeymapError::Key(path, error) => write!(f, "keymap '{}': {}", path, error),
+        }
+    }
+}
+
+impl std::error::Error for KeymapError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"
+        [global]
+        save = "ctrl+s"
+        quit = ["ctrl+q", "ctrl+x ctrl+c"]
+
+        [kind.input]
+        clear = "ctrl+u"
+
+        [container.sidebar]
+        collapse = "ctrl+b"
+    "#;
+
+    fn bound(keymap: &Keymap, context: KeyContext, keys: &str) -> Option<String> {
+        let keys = KeySequence::parse(keys).unwrap();
+        let entry = keymap.entries().iter();
+        let mut entry = entry.filter(|entry| entry.context == context && entry.keys == keys);
+        entry.next().map(|entry| entry.action.clone())
+    }
+
+    #[test]
+    fn parses_every_context() {
+        let keymap = Keymap::from_toml(SOURCE).unwrap();
+        assert_eq!(keymap.entries().len(), 5);
+        let global = |keys| bound(&keymap, KeyContext::Global, keys);
+        assert_eq!(global("ctrl+s").as_deref(), Some("save"));
+        assert_eq!(global("ctrl+q").as_deref(), Some("quit"));
+        assert_eq!(global("ctrl+x ctrl+c").as_deref(), Some("quit"));
+        let input = KeyContext::Kind(WidgetKind::Input);
+        assert_eq!(bound(&keymap, input, "ctrl+u").as_deref(), Some("clear"));
+        let sidebar = KeyContext::Container("sidebar".to_string());
+        assert_eq!(
+            bound(&keymap, sidebar, "ctrl+b").as_deref(),
+            Some("collapse")
+        );
+    }
+
+    #[test]
+    fn loads_files() {
+        let path = std::env::temp_dir().join(format!("ccui-keymap-{}.toml", std::process::id()));
+        std::fs::write(&path, SOURCE).unwrap();
+        let loaded = Keymap::load(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, Keymap::from_toml(SOURCE));
+
+        let missing = Keymap::load(path);
+        assert!(matches!(missing, Err(KeymapError::Io(_))));
+    }
+
+    #[test]
+    fn reports_unknown_keys() {
+        let error = Keymap::from_toml("[global]\nsave = \"ctrl+nope\"").unwrap_err();
+        assert!(matches!(&error, KeymapError::Key(path, _) if path == "global.save"));
+        assert_eq!(
+            error.to_string(),
+            "keymap 'global.save': invalid key 'ctrl+nope': unknown key"
+        );
+    }
+
+    #[test]
+    fn reports_bad_sequences() {
+        let error = Keymap::from_toml("[global]\nquit = [\"ctrl+q\", \"ctrl+x bogus+c\"]");
+        assert!(matches!(error, Err(KeymapError::Key(path, _)) if path == "global.quit"));
+        let error = Keymap::from_toml("[global]\nquit = \" \"").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "keymap 'global.quit': invalid key ' ': empty key sequence"
+        );
+        let error = Keymap::from_toml("[global]\nquit = 3").unwrap_err();
+        assert_eq!(
+            error,
+            KeymapError::invalid("global.quit", "expected a key string or list")
+        );
+        let error = Keymap::from_toml("[global\nquit = \"ctrl+q\"");
+        assert!(matches!(error, Err(KeymapError::Toml(_))));
+    }
+
+    #[test]
+    fn reports_unknown_contexts() {
+        let error = Keymap::from_toml("[kind.spinner]\nspin = \"s\"").unwrap_err();
+        assert_eq!(
+            error,
+            KeymapError::invalid("kind.spinner", "unknown widget kind")
+        );
+        let error = Keymap::from_toml("[local]\nsave = \"ctrl+s\"").unwrap_err();
+        assert_eq!(error, KeymapError::invalid("local", "unknown section"));
+    }
+}
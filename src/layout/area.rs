@@ -11,13 +11,21 @@ pub(super) fn is_floating(child: &Node) -> bool {
     child.style.position_mode == crate::style::PositionMode::Floating
 }
 
+/// Check if node is removed from layout (`display: none`).
+#[inline]
+pub(super) fn is_hidden(child: &Node) -> bool {
+    !child.style.is_displayed()
+}
+
 /// Calculate content area (applying border and padding).
 fn content_area(style: &Style, parent_area: Rect) -> Rect {
-    // !Visible containers' children area's offset will be handled by ScrollView
+    // !Visible containers lay children out in the ScrollView's local
+    // coordinates; its position and scroll offset are applied when rendering
     let real_content = if style.overflow == Overflow::Visible {
         shrink_and_offset_border(style, parent_area)
     } else {
-        shrink_border(style, parent_area)
+        let inner = shrink_border(style, parent_area);
+        Rect::new(0, 0, inner.width, inner.height)
     };
 
     Rect::new(
@@ -32,36 +40,29 @@ fn content_area(style: &Style, parent_area: Rect) -> Rect {
     )
 }
 
-/// Shrink area by border.
+/// Shrink area by border (only the sides that draw one).
 pub fn shrink_border(style: &Style, area: Rect) -> Rect {
-    if style.border_type.is_none() {
-        return area;
-    }
+    let border = style.border_insets();
     Rect::new(
         area.x,
         area.y,
-        area.width.saturating_sub(2),
-        area.height.saturating_sub(2),
+        area.width.saturating_sub(border.left + border.right),
+        area.height.saturating_sub(border.top + border.bottom),
     )
 }
 
 pub fn offset_border(style: &Style, area: Rect) -> Rect {
-    if style.border_type.is_none() {
-        return area;
-    }
-    Rect::new(area.x + 1, area.y + 1, area.width, area.height)
+    let border = style.border_insets();
+    Rect::new(
+        area.x + border.left,
+        area.y + border.top,
+        area.width,
+        area.height,
+    )
 }
 
 pub fn shrink_and_offset_border(style: &Style, area: Rect) -> Rect {
-    if style.border_type.is_none() {
-        return area;
-    }
-    Rect::new(
-        area.x + 1,
-        area.y + 1,
-        area.width.saturating_sub(2),
-        area.height.saturating_sub(2),
-    )
+    offset_border(style, shrink_border(style, area))
 }
 
 /// Calculate child layout areas (for Node::layout).
@@ -76,9 +77,18 @@ pub fn calculate_children_areas(style: &Style, parent_area: Rect, children: &[No
         direction: style.flex_direction,
     };
 
-    // Check if all children are floating
-    if children.iter().all(is_floating) {
-        return vec![parent_area; children.len()];
+    // Check if all children are floating (or hidden)
+    if children.iter().all(|c| is_floating(c) || is_hidden(c)) {
+        return children
+            .iter()
+            .map(|c| {
+                if is_hidden(c) {
+                    Rect::default()
+                } else {
+                    parent_area
+                }
+            })
+            .collect();
     }
 
     // Calculate sizes
@@ -90,6 +100,9 @@ pub fn calculate_children_areas(style: &Style, parent_area: Rect, children: &[No
         .iter()
         .zip(sizes)
         .map(|(child, size)| {
+            if is_hidden(child) {
+                return Rect::default();
+            }
             if is_floating(child) {
                 return parent_area;
             }
@@ -7,19 +7,15 @@ use super::context::LayoutContext;
 
 /// Calculate node content size (for Auto layout and future min/max constraints).
 pub fn calculate_content_size(node: &Node, available_area: Rect) -> (u16, u16) {
-    if let Some(widget) = &node.widget {
+    if !node.style.is_displayed() {
+        // display: none takes no space
+        (0, 0)
+    } else if let Some(widget) = &node.widget {
         // Leaf: widget content + padding + border
         let (w, h) = widget.content_size(available_area);
-        let border_w = if node.style.border_type.is_some() {
-            2
-        } else {
-            0
-        };
-        let border_h = if node.style.border_type.is_some() {
-            2
-        } else {
-            0
-        };
+        let border = node.style.border_insets();
+        let border_w = border.left + border.right;
+        let border_h = border.top + border.bottom;
 
         (
             w + node.style.padding.left + node.style.padding.right + border_w,
@@ -36,16 +32,9 @@ pub fn calculate_content_size(node: &Node, available_area: Rect) -> (u16, u16) {
         let (children_w, children_h) = calculate_container_content(node, &ctx);
 
         // Add padding and border
-        let border_w = if node.style.border_type.is_some() {
-            2
-        } else {
-            0
-        };
-        let border_h = if node.style.border_type.is_some() {
-            2
-        } else {
-            0
-        };
+        let border = node.style.border_insets();
+        let border_w = border.left + border.right;
+        let border_h = border.top + border.bottom;
 
         (
             children_w + node.style.padding.left + node.style.padding.right + border_w,
@@ -69,7 +58,7 @@ fn calculate_container_content(node: &Node, ctx: &LayoutContext) -> (u16, u16) {
             let mut total_width = 0u16;
             let mut max_height = 0u16;
 
-            for child in &node.children {
+            for child in node.children.iter().filter(|c| !super::area::is_hidden(c)) {
                 let (w, h) = calculate_content_size(child, inner_available);
                 total_width += w + node.style.gap;
                 max_height = max_height.max(h);
@@ -82,7 +71,7 @@ fn calculate_container_content(node: &Node, ctx: &LayoutContext) -> (u16, u16) {
             let mut max_width = 0u16;
             let mut total_height = 0u16;
 
-            for child in &node.children {
+            for child in node.children.iter().filter(|c| !super::area::is_hidden(c)) {
                 let (w, h) = calculate_content_size(child, inner_available);
                 max_width = max_width.max(w);
                 total_height += h + node.style.gap;
@@ -110,7 +99,7 @@ fn calc_tiled_sizes(_style: &Style, children: &[Node], ctx: &LayoutContext) -> V
     // Calculate fixed size sum + flexible node count
     let (fixed_sum, flexible_count) = children
         .iter()
-        .filter(|c| !super::area::is_floating(c))
+        .filter(|c| !super::area::is_floating(c) && !super::area::is_hidden(c))
         .fold((0u16, 0u16), |(sum, count), child| {
             let size = ctx.get_child_size(child);
             if size > 0 {
@@ -130,7 +119,7 @@ fn calc_tiled_sizes(_style: &Style, children: &[Node], ctx: &LayoutContext) -> V
     children
         .iter()
         .map(|child| {
-            if super::area::is_floating(child) {
+            if super::area::is_floating(child) || super::area::is_hidden(child) {
                 0
             } else {
                 let size = ctx.get_child_size(child);
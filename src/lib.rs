@@ -13,7 +13,7 @@
 //!     doc.add_widget("title", Text::new("Hello"))?;
 //!
 //!     // Add container with children
-//!     let row = doc.add_container("row", Style::new().row())?;
+//!     let mut row = doc.add_container("row", Style::new().row())?;
 //!     row.add_widget("btn", Text::new("Click me"))?;
 //!
 //!     // Add event listener
@@ -35,12 +35,35 @@
 //! ```
 
 // Core modules
+pub mod animation;
+pub mod commands;
 mod document;
 pub mod event;
 mod internal;
+pub mod key;
+pub mod keymap;
 pub mod layout;
 pub mod style;
+pub mod stylesheet;
+pub mod theme;
+pub mod timer;
 pub mod util;
 pub mod widget;
 
+pub use animation::{Animation, Easing};
+pub use commands::UiCommands;
 pub use document::{Container, ContainerHandle, Document, Ui, WidgetHandle};
+pub use event::{
+    CustomEvent, Event, EventContext, EventPhase, EventStream, EventType, ListenerGuard,
+    WidgetEvent,
+};
+pub use key::{KeyBinding, KeySequence};
+pub use keymap::{KeyContext, Keymap};
+pub use style::Style;
+pub use stylesheet::Stylesheet;
+pub use theme::{Theme, ThemeColor};
+pub use timer::{TimerContext, TimerId};
+pub use util::shared::Shared;
+pub use widget::{
+    Button, CheckState, Checkbox, Divider, Input, List, RadioGroup, Switch, Table, Text, Textarea,
+};
@@ -1,3 +1,5 @@
+use crate::stylesheet::Declaration;
+use crate::theme::{Theme, ThemeColor};
 use ratatui::layout::Rect;
 
 /// Color for background and foreground.
@@ -14,8 +16,44 @@ pub enum Color {
     Cyan,
     White,
     Rgb(u8, u8, u8),
+    /// Semantic color resolved from the active theme at draw time.
+    Theme(ThemeColor),
 }
 
+impl Color {
+    /// Resolve theme references against the given theme.
+    pub fn resolve(self, theme: &Theme) -> Color {
+        match self {
+            Color::Theme(slot) => theme.get(slot),
+            color => color,
+        }
+    }
+
+    /// RGB value of a resolved color (named colors use xterm's palette).
+    /// `Reset` and unresolved theme colors have none.
+    pub fn to_rgb(self) -> Option<(u8, u8, u8)> {
+        Some(match self {
+            Color::Black => (0, 0, 0),
+            Color::Red => (205, 0, 0),
+            Color::Green => (0, 205, 0),
+            Color::Yellow => (205, 205, 0),
+            Color::Blue => (0, 0, 238),
+            Color::Magenta => (205, 0, 205),
+            Color::Cyan => (0, 205, 205),
+            Color::White => (229, 229, 229),
+            Color::Rgb(r, g, b) => (r, g, b),
+            Color::Reset | Color::Theme(_) => return None,
+        })
+    }
+
+    /// Convert to a ratatui color, resolving theme references against `theme`.
+    pub fn to_ratatui(self, theme: &Theme) -> ratatui::style::Color {
+        self.resolve(theme).into()
+    }
+}
+
+/// Theme references that weren't resolved use the default theme; use
+/// `Color::to_ratatui` to convert with the active theme.
 impl From<Color> for ratatui::style::Color {
     fn from(ccui_color: Color) -> Self {
         match ccui_color {
@@ -29,6 +67,7 @@ impl From<Color> for ratatui::style::Color {
             Color::Cyan => ratatui::style::Color::Cyan,
             Color::White => ratatui::style::Color::White,
             Color::Rgb(r, g, b) => ratatui::style::Color::Rgb(r, g, b),
+            Color::Theme(slot) => Theme::default().get(slot).into(),
         }
     }
 }
@@ -73,6 +112,93 @@ pub enum BorderType {
     Thick,   // ━ ┃ ┏ ┓ ┗ ┛
 }
 
+/// Which sides of a container draw a border.
+///
+/// Combine sides with `|`, e.g. `BorderSides::TOP | BorderSides::BOTTOM`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BorderSides {
+    pub top: bool,
+    pub right: bool,
+    pub bottom: bool,
+    pub left: bool,
+}
+
+impl BorderSides {
+    pub const ALL: Self = Self::new(true, true, true, true);
+    pub const NONE: Self = Self::new(false, false, false, false);
+    pub const TOP: Self = Self::new(true, false, false, false);
+    pub const RIGHT: Self = Self::new(false, true, false, false);
+    pub const BOTTOM: Self = Self::new(false, false, true, false);
+    pub const LEFT: Self = Self::new(false, false, false, true);
+
+    pub const fn new(top: bool, right: bool, bottom: bool, left: bool) -> Self {
+        BorderSides {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+}
+
+impl Default for BorderSides {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl std::ops::BitOr for BorderSides {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        BorderSides {
+            top: self.top || rhs.top,
+            right: self.right || rhs.right,
+            bottom: self.bottom || rhs.bottom,
+            left: self.left || rhs.left,
+        }
+    }
+}
+
+impl From<BorderSides> for ratatui::widgets::Borders {
+    fn from(sides: BorderSides) -> Self {
+        use ratatui::widgets::Borders;
+        let mut borders = Borders::NONE;
+        if sides.top {
+            borders |= Borders::TOP;
+        }
+        if sides.right {
+            borders |= Borders::RIGHT;
+        }
+        if sides.bottom {
+            borders |= Borders::BOTTOM;
+        }
+        if sides.left {
+            borders |= Borders::LEFT;
+        }
+        borders
+    }
+}
+
+/// Horizontal alignment for border titles and footers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+impl From<TextAlign> for ratatui::layout::Alignment {
+    fn from(align: TextAlign) -> Self {
+        match align {
+            TextAlign::Left => ratatui::layout::Alignment::Left,
+            TextAlign::Center => ratatui::layout::Alignment::Center,
+            TextAlign::Right => ratatui::layout::Alignment::Right,
+        }
+    }
+}
+
 /// Overflow mode for containers.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Overflow {
@@ -83,6 +209,16 @@ pub enum Overflow {
     Auto,
 }
 
+/// Display mode - whether a node takes part in layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Display {
+    /// Laid out normally (default).
+    #[default]
+    Flex,
+    /// Removed from layout, render and hit testing.
+    None,
+}
+
 /// Spacing offset (padding).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct RectOffset {
@@ -112,16 +248,70 @@ impl RectOffset {
     }
 }
 
+/// Interaction state of a node, used for pseudo-state styles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NodeState {
+    /// The node has keyboard focus.
+    Focus,
+    /// The mouse is over the node or one of its descendants.
+    Hover,
+    /// The left mouse button is held down on the node or one of its descendants.
+    Active,
+    /// The node is disabled (`Style::disabled`).
+    Disabled,
+}
+
+/// Style overrides applied while a node is in a given state.
+///
+/// Overrides are applied in the order hover, focus, active, disabled,
+/// so later states win when several apply at once.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct StateStyles {
+    pub hover: Vec<Declaration>,
+    pub focus: Vec<Declaration>,
+    pub active: Vec<Declaration>,
+    pub disabled: Vec<Declaration>,
+}
+
+impl StateStyles {
+    pub fn get(&self, state: NodeState) -> &[Declaration] {
+        match state {
+            NodeState::Hover => &self.hover,
+            NodeState::Focus => &self.focus,
+            NodeState::Active => &self.active,
+            NodeState::Disabled => &self.disabled,
+        }
+    }
+
+    fn get_mut(&mut self, state: NodeState) -> &mut Vec<Declaration> {
+        match state {
+            NodeState::Hover => &mut self.hover,
+            NodeState::Focus => &mut self.focus,
+            NodeState::Active => &mut self.active,
+            NodeState::Disabled => &mut self.disabled,
+        }
+    }
+}
+
 /// Style properties - shared by all nodes.
 ///
 /// - Container nodes use `flex_direction`, `gap`, and `padding` for layout.
 /// - Widget nodes may use `padding` to inset their rendering area.
-#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Style {
     pub flex_direction: FlexDirection,
     pub gap: u16,
     pub padding: RectOffset,
     pub border_type: Option<BorderType>,
+    pub border_sides: BorderSides,
+    pub border_color: Option<Color>,
+    // Border color while the node has focus (falls back to border_color)
+    pub border_focus_color: Option<Color>,
+    // Text drawn on the top / bottom border
+    pub title: Option<String>,
+    pub title_align: TextAlign,
+    pub footer: Option<String>,
+    pub footer_align: TextAlign,
     pub layout_mode: LayoutMode,
 
     // Position mode (for floating windows)
@@ -134,11 +324,71 @@ pub struct Style {
     // Background color (None = transparent)
     pub bg_color: Option<Color>,
 
+    // Foreground (text) color (None = terminal default)
+    pub fg_color: Option<Color>,
+
     // Z-index for stacking order (higher values render on top)
     pub z_index: i32,
 
     // Overflow mode (default: visible)
     pub overflow: Overflow,
+
+    // Invisible nodes keep their layout space but are not drawn or hit-testable
+    pub visible: bool,
+
+    // Display::None removes the node from layout entirely
+    pub display: Display,
+
+    // Disabled nodes can't be focused and match the `:disabled` state
+    pub disabled: bool,
+
+    // Whether the node can take keyboard focus (click, Tab, Document::focus)
+    pub focusable: bool,
+
+    // Tab order: positive values first (ascending), then 0 in document order;
+    // negative values are skipped by Tab but can still be focused otherwise
+    pub tab_index: i32,
+
+    // Modal nodes confine focus traversal and mouse hit testing to their subtree
+    pub modal: bool,
+
+    // Overrides for :hover, :focus, :active and :disabled
+    pub states: StateStyles,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Style {
+            flex_direction: FlexDirection::default(),
+            gap: 0,
+            padding: RectOffset::default(),
+            border_type: None,
+            border_sides: BorderSides::default(),
+            border_color: None,
+            border_focus_color: None,
+            title: None,
+            title_align: TextAlign::default(),
+            footer: None,
+            footer_align: TextAlign::default(),
+            layout_mode: LayoutMode::default(),
+            position_mode: PositionMode::default(),
+            x: 0,
+            y: 0,
+            width: 0,
+            height: 0,
+            bg_color: None,
+            fg_color: None,
+            z_index: 0,
+            overflow: Overflow::default(),
+            visible: true,
+            display: Display::default(),
+            disabled: false,
+            focusable: false,
+            tab_index: 0,
+            modal: false,
+            states: StateStyles::default(),
+        }
+    }
 }
 
 impl Style {
@@ -181,6 +431,71 @@ impl Style {
         self
     }
 
+    /// Draw the border only on the given sides.
+    pub fn border_sides(mut self, sides: BorderSides) -> Self {
+        self.border_sides = sides;
+        self
+    }
+
+    /// Set border color.
+    pub fn border_color(mut self, color: Color) -> Self {
+        self.border_color = Some(color);
+        self
+    }
+
+    /// Set border color used while the node has focus.
+    pub fn border_focus_color(mut self, color: Color) -> Self {
+        self.border_focus_color = Some(color);
+        self
+    }
+
+    /// Set the title drawn on the top border.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set title alignment.
+    pub fn title_align(mut self, align: TextAlign) -> Self {
+        self.title_align = align;
+        self
+    }
+
+    /// Set the footer drawn on the bottom border.
+    pub fn footer(mut self, footer: impl Into<String>) -> Self {
+        self.footer = Some(footer.into());
+        self
+    }
+
+    /// Set footer alignment.
+    pub fn footer_align(mut self, align: TextAlign) -> Self {
+        self.footer_align = align;
+        self
+    }
+
+    /// Border width on each side (1 per drawn side, 0 without border).
+    pub fn border_insets(&self) -> RectOffset {
+        if self.border_type.is_none() {
+            return RectOffset::default();
+        }
+        let sides = self.border_sides;
+        RectOffset::new(
+            sides.top as u16,
+            sides.right as u16,
+            sides.bottom as u16,
+            sides.left as u16,
+        )
+    }
+
+    /// Border color for the given focus state.
+    pub fn border_color_for(&self, is_focused: bool) -> Option<Color> {
+        if is_focused {
+            self.border_focus_color.or(self.border_color)
+        } else {
+            self.border_color
+        }
+    }
+
     pub fn tiled(mut self) -> Self {
         self.layout_mode = LayoutMode::Tiled;
         self
@@ -227,6 +542,188 @@ impl Style {
         self
     }
 
+    /// Set foreground (text) color.
+    pub fn fg_color(mut self, color: Color) -> Self {
+        self.fg_color = Some(color);
+        self
+    }
+
+    /// Set visibility (invisible nodes keep their layout space).
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Set display mode (`Display::None` removes the node from layout).
+    pub fn display(mut self, display: Display) -> Self {
+        self.display = display;
+        self
+    }
+
+    /// Whether the node takes part in layout.
+    pub fn is_displayed(&self) -> bool {
+        self.display != Display::None
+    }
+
+    /// Whether the node is drawn and hit-testable.
+    pub fn is_shown(&self) -> bool {
+        self.is_displayed() && self.visible
+    }
+
+    /// Mark the node as disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Allow the node to take keyboard focus.
+    pub fn focusable(mut self, focusable: bool) -> Self {
+        self.focusable = focusable;
+        self
+    }
+
+    /// Set the Tab order index.
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
+    /// Trap focus and mouse input inside this node while it is shown.
+    pub fn modal(mut self, modal: bool) -> Self {
+        self.modal = modal;
+        self
+    }
+
+    /// Override style properties while the node is hovered.
+    ///
+    /// ```rust
+    /// # use ccui::{Style, style::Color};
+    /// let style = Style::new().on_hover(|s| s.bg_color(Color::Blue));
+    /// ```
+    pub fn on_hover(self, f: impl FnOnce(Style) -> Style) -> Self {
+        self.on_state(NodeState::Hover, f)
+    }
+
+    /// Override style properties while the node has focus.
+    pub fn on_focus(self, f: impl FnOnce(Style) -> Style) -> Self {
+        self.on_state(NodeState::Focus, f)
+    }
+
+    /// Override style properties while the node is pressed.
+    pub fn on_active(self, f: impl FnOnce(Style) -> Style) -> Self {
+        self.on_state(NodeState::Active, f)
+    }
+
+    /// Override style properties while the node is disabled.
+    pub fn on_disabled(self, f: impl FnOnce(Style) -> Style) -> Self {
+        self.on_state(NodeState::Disabled, f)
+    }
+
+    /// Record the properties `f` changes as overrides for `state`.
+    fn on_state(mut self, state: NodeState, f: impl FnOnce(Style) -> Style) -> Self {
+        let changed = f(self.clone());
+        let declarations = self.diff(&changed);
+        self.states.get_mut(state).extend(declarations);
+        self
+    }
+
+    /// Declarations that turn `self` into `other` (state overrides are ignored).
+    pub fn diff(&self, other: &Style) -> Vec<Declaration> {
+        let mut out = Vec::new();
+        if self.flex_direction != other.flex_direction {
+            out.push(Declaration::FlexDirection(other.flex_direction));
+        }
+        if self.gap != other.gap {
+            out.push(Declaration::Gap(other.gap));
+        }
+        if self.padding != other.padding {
+            out.push(Declaration::Padding(other.padding));
+        }
+        if self.border_type != other.border_type {
+            out.push(Declaration::Border(other.border_type));
+        }
+        if self.border_sides != other.border_sides {
+            out.push(Declaration::BorderSides(other.border_sides));
+        }
+        if self.border_color != other.border_color {
+            out.push(Declaration::BorderColor(other.border_color));
+        }
+        if self.border_focus_color != other.border_focus_color {
+            out.push(Declaration::BorderFocusColor(other.border_focus_color));
+        }
+        if self.title != other.title {
+            out.push(Declaration::Title(other.title.clone()));
+        }
+        if self.title_align != other.title_align {
+            out.push(Declaration::TitleAlign(other.title_align));
+        }
+        if self.footer != other.footer {
+            out.push(Declaration::Footer(other.footer.clone()));
+        }
+        if self.footer_align != other.footer_align {
+            out.push(Declaration::FooterAlign(other.footer_align));
+        }
+        if self.layout_mode != other.layout_mode {
+            out.push(Declaration::LayoutMode(other.layout_mode));
+        }
+        if self.position_mode != other.position_mode {
+            out.push(Declaration::PositionMode(other.position_mode));
+        }
+        if self.x != other.x {
+            out.push(Declaration::X(other.x));
+        }
+        if self.y != other.y {
+            out.push(Declaration::Y(other.y));
+        }
+        if self.width != other.width {
+            out.push(Declaration::Width(other.width));
+        }
+        if self.height != other.height {
+            out.push(Declaration::Height(other.height));
+        }
+        if self.bg_color != other.bg_color {
+            out.push(Declaration::BgColor(other.bg_color));
+        }
+        if self.fg_color != other.fg_color {
+            out.push(Declaration::FgColor(other.fg_color));
+        }
+        if self.z_index != other.z_index {
+            out.push(Declaration::ZIndex(other.z_index));
+        }
+        if self.overflow != other.overflow {
+            out.push(Declaration::Overflow(other.overflow));
+        }
+        if self.visible != other.visible {
+            out.push(Declaration::Visible(other.visible));
+        }
+        if self.display != other.display {
+            out.push(Declaration::Display(other.display));
+        }
+        if self.disabled != other.disabled {
+            out.push(Declaration::Disabled(other.disabled));
+        }
+        if self.focusable != other.focusable {
+            out.push(Declaration::Focusable(other.focusable));
+        }
+        if self.tab_index != other.tab_index {
+            out.push(Declaration::TabIndex(other.tab_index));
+        }
+        if self.modal != other.modal {
+            out.push(Declaration::Modal(other.modal));
+        }
+        out
+    }
+
+    /// Resolve all theme color references against the given theme.
+    pub fn resolve(&self, theme: &Theme) -> Style {
+        let mut style = self.clone();
+        style.bg_color = style.bg_color.map(|c| c.resolve(theme));
+        style.fg_color = style.fg_color.map(|c| c.resolve(theme));
+        style.border_color = style.border_color.map(|c| c.resolve(theme));
+        style.border_focus_color = style.border_focus_color.map(|c| c.resolve(theme));
+        style
+    }
+
     /// Set z-index for stacking order (higher values render on top).
     pub fn z_index(mut self, z: i32) -> Self {
         self.z_index = z;
@@ -0,0 +1,239 @@
+//! Class-based stylesheets.
+//!
+//! A [`Stylesheet`] holds rules that match nodes by id, class, widget kind and
+//! position in the tree. Matching rules are ordered by specificity and then
+//! source order.
+//!
+//! The cascade, from lowest to highest priority:
+//! 1. the style the node was created with (a widget's built-in style, or a
+//!    container's creation style)
+//! 2. stylesheet rules
+//! 3. inline style properties that differ from those defaults
+//! 4. inline state overrides (`Style::states`) for the node's current states
+//!
+//! ```text
+//! /* every bordered panel */
+//! .panel { border: rounded; padding: 1; }
+//! #sidebar > text { color: muted; }
+//! input.wide { width: 30; }
+//! .panel:focus { bg: selection; }
+//! ```
+//!
+//! Supported pseudo-classes: `:focus`, `:hover`, `:active`, `:disabled`.
+
+mod parser;
+mod selector;
+
+pub use selector::{Combinator, Compound, KindSelector, Selector, SelectorTarget};
+
+use crate::style::{
+    BorderSides, BorderType, Color, Display, FlexDirection, LayoutMode, Overflow, PositionMode,
+    RectOffset, Style, TextAlign,
+};
+use std::fmt;
+use std::str::FromStr;
+
+/// A single property assignment inside a rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Declaration {
+    FlexDirection(FlexDirection),
+    Gap(u16),
+    Padding(RectOffset),
+    Border(Option<BorderType>),
+    BorderSides(BorderSides),
+    BorderColor(Option<Color>),
+    BorderFocusColor(Option<Color>),
+    Title(Option<String>),
+    TitleAlign(TextAlign),
+    Footer(Option<String>),
+    FooterAlign(TextAlign),
+    LayoutMode(LayoutMode),
+    PositionMode(PositionMode),
+    X(u16),
+    Y(u16),
+    Width(u16),
+    Height(u16),
+    BgColor(Option<Color>),
+    FgColor(Option<Color>),
+    ZIndex(i32),
+    Overflow(Overflow),
+    Visible(bool),
+    Display(Display),
+    Disabled(bool),
+    Focusable(bool),
+    TabIndex(i32),
+    Modal(bool),
+}
+
+impl Declaration {
+    /// Every property of a style as a declaration, in a fixed order.
+    pub(crate) fn all(style: &Style) -> [Declaration; 27] {
+        [
+            Declaration::FlexDirection(style.flex_direction),
+            Declaration::Gap(style.gap),
+            Declaration::Padding(style.padding),
+            Declaration::Border(style.border_type),
+            Declaration::BorderSides(style.border_sides),
+            Declaration::BorderColor(style.border_color),
+            Declaration::BorderFocusColor(style.border_focus_color),
+            Declaration::Title(style.title.clone()),
+            Declaration::TitleAlign(style.title_align),
+            Declaration::Footer(style.footer.clone()),
+            Declaration::FooterAlign(style.footer_align),
+            Declaration::LayoutMode(style.layout_mode),
+            Declaration::PositionMode(style.position_mode),
+            Declaration::X(style.x),
+            Declaration::Y(style.y),
+            Declaration::Width(style.width),
+            Declaration::Height(style.height),
+            Declaration::BgColor(style.bg_color),
+            Declaration::FgColor(style.fg_color),
+            Declaration::ZIndex(style.z_index),
+            Declaration::Overflow(style.overflow),
+            Declaration::Visible(style.visible),
+            Declaration::Display(style.display),
+            Declaration::Disabled(style.disabled),
+            Declaration::Focusable(style.focusable),
+            Declaration::TabIndex(style.tab_index),
+            Declaration::Modal(style.modal),
+        ]
+    }
+
+    /// Apply this declaration to a style.
+    pub fn apply(&self, style: &mut Style) {
+        match self {
+            Declaration::FlexDirection(v) => style.flex_direction = *v,
+            Declaration::Gap(v) => style.gap = *v,
+            Declaration::Padding(v) => style.padding = *v,
+            Declaration::Border(v) => style.border_type = *v,
+            Declaration::BorderSides(v) => style.border_sides = *v,
+            Declaration::BorderColor(v) => style.border_color = *v,
+            Declaration::BorderFocusColor(v) => style.border_focus_color = *v,
+            Declaration::Title(v) => style.title = v.clone(),
+            Declaration::TitleAlign(v) => style.title_align = *v,
+            Declaration::Footer(v) => style.footer = v.clone(),
+            Declaration::FooterAlign(v) => style.footer_align = *v,
+            Declaration::LayoutMode(v) => style.layout_mode = *v,
+            Declaration::PositionMode(v) => style.position_mode = *v,
+            Declaration::X(v) => style.x = *v,
+            Declaration::Y(v) => style.y = *v,
+            Declaration::Width(v) => style.width = *v,
+            Declaration::Height(v) => style.height = *v,
+            Declaration::BgColor(v) => style.bg_color = *v,
+            Declaration::FgColor(v) => style.fg_color = *v,
+            Declaration::ZIndex(v) => style.z_index = *v,
+            Declaration::Overflow(v) => style.overflow = *v,
+            Declaration::Visible(v) => style.visible = *v,
+            Declaration::Display(v) => style.display = *v,
+            Declaration::Disabled(v) => style.disabled = *v,
+            Declaration::Focusable(v) => style.focusable = *v,
+            Declaration::TabIndex(v) => style.tab_index = *v,
+            Declaration::Modal(v) => style.modal = *v,
+        }
+    }
+}
+
+/// A selector with the declarations it applies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    pub selector: Selector,
+    pub declarations: Vec<Declaration>,
+}
+
+/// Error produced when parsing a stylesheet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StylesheetError {
+    /// 1-based line number of the offending text.
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for StylesheetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "stylesheet error on line {}: {}",
+            self.line, self.message
+        )
+    }
+}
+
+impl std::error::Error for StylesheetError {}
+
+/// An ordered collection of style rules.
+///
+/// Set it with `Document::set_stylesheet`. Rules override a node's defaults
+/// but not the properties set on its inline style.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Stylesheet {
+    rules: Vec<Rule>,
+}
+
+impl Stylesheet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse a stylesheet from CSS-like text.
+    pub fn parse(source: &str) -> Result<Self, StylesheetError> {
+        Ok(Stylesheet {
+            rules: parser::parse_rules(source)?,
+        })
+    }
+
+    /// Add a rule programmatically.
+    ///
+    /// `selector` uses the same syntax as the text format (comma lists allowed).
+    pub fn add_rule(
+        &mut self,
+        selector: &str,
+        declarations: Vec<Declaration>,
+    ) -> Result<(), StylesheetError> {
+        for selector in parser::parse_selector_list(selector)
+            .map_err(|message| StylesheetError { line: 1, message })?
+        {
+            self.rules.push(Rule {
+                selector,
+                declarations: declarations.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Append all rules of another stylesheet (they take precedence on ties).
+    pub fn extend(&mut self, other: Stylesheet) {
+        self.rules.extend(other.rules);
+    }
+
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Apply every rule matching the last node of `path` to `style`.
+    pub(crate) fn apply(&self, path: &[SelectorTarget], style: &mut Style) {
+        let mut matched: Vec<&Rule> = self
+            .rules
+            .iter()
+            .filter(|rule| rule.selector.matches(path))
+            .collect();
+        // Stable sort keeps source order among equal specificity
+        matched.sort_by_key(|rule| rule.selector.specificity());
+        for rule in matched {
+            for declaration in &rule.declarations {
+                declaration.apply(style);
+            }
+        }
+    }
+}
+
+impl FromStr for Stylesheet {
+    type Err = StylesheetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
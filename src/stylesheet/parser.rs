@@ -0,0 +1,589 @@
+use super::selector::{Combinator, Compound, KindSelector, Selector};
+use super::{Declaration, Rule, StylesheetError};
+use crate::style::{
+    BorderSides, BorderType, Color, Display, FlexDirection, LayoutMode, NodeState, Overflow,
+    PositionMode, RectOffset, TextAlign,
+};
+use crate::theme::ThemeColor;
+use crate::widget::WidgetKind;
+
+/// Parse the full stylesheet text into rules.
+pub(super) fn parse_rules(source: &str) -> Result<Vec<Rule>, StylesheetError> {
+    let source = strip_comments(source);
+    let mut rules = Vec::new();
+    let mut rest = source.as_str();
+    let mut offset = 0;
+
+    while let Some(open) = rest.find('{') {
+        let line = line_at(&source, offset + open);
+        let selector_text = rest[..open].trim();
+        let Some(close) = find_unquoted(&rest[open..], '}') else {
+            return Err(StylesheetError {
+                line,
+                message: "missing closing '}'".to_string(),
+            });
+        };
+        let body = &rest[open + 1..open + close];
+
+        let selectors = parse_selector_list(selector_text)
+            .map_err(|message| StylesheetError { line, message })?;
+
+        let mut declarations = Vec::new();
+        let mut body_offset = offset + open + 1;
+        for item in split_unquoted(body, ';') {
+            let item_line = line_at(
+                &source,
+                body_offset + (item.len() - item.trim_start().len()),
+            );
+            body_offset += item.len() + 1;
+            let item = item.trim();
+            if item.is_empty() {
+                continue;
+            }
+            let Some((name, value)) = item.split_once(':') else {
+                return Err(StylesheetError {
+                    line: item_line,
+                    message: format!("expected 'property: value', found '{}'", item),
+                });
+            };
+            let declaration = parse_declaration(name.trim(), value.trim()).map_err(|message| {
+                StylesheetError {
+                    line: item_line,
+                    message,
+                }
+            })?;
+            declarations.push(declaration);
+        }
+
+        for selector in selectors {
+            rules.push(Rule {
+                selector,
+                declarations: declarations.clone(),
+            });
+        }
+
+        offset += open + close + 1;
+        rest = &rest[open + close + 1..];
+    }
+
+    if !rest.trim().is_empty() {
+        return Err(StylesheetError {
+            line: line_at(&source, offset + (rest.len() - rest.trim_start().len())),
+            message: format!("unexpected text '{}'", rest.trim()),
+        });
+    }
+
+    Ok(rules)
+}
+
+/// Replace `/* ... */` comments with spaces, keeping line numbers intact.
+///
+/// Comment markers inside quoted strings are left alone.
+fn strip_comments(source: &str) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut chars = source.chars().peekable();
+    let mut quote = None;
+    while let Some(c) = chars.next() {
+        if quote.is_none() && c == '/' && chars.peek() == Some(&'*') {
+            chars.next();
+            out.push_str("  ");
+            let mut prev = ' ';
+            for c in chars.by_ref() {
+                out.push(if c == '\n' { '\n' } else { ' ' });
+                if prev == '*' && c == '/' {
+                    break;
+                }
+                prev = c;
+            }
+            continue;
+        }
+        quote = match quote {
+            Some(q) if c == q => None,
+            None if c == '"' || c == '\'' => Some(c),
+            quote => quote,
+        };
+        out.push(c);
+    }
+    out
+}
+
+/// Byte offset of the first `target` that isn't inside a quoted string.
+fn find_unquoted(text: &str, target: char) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in text.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == target => return Some(i),
+            None => {}
+        }
+    }
+    None
+}
+
+/// Like `str::split`, but separators inside quoted strings don't count.
+fn split_unquoted(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut rest = text;
+    while let Some(end) = find_unquoted(rest, separator) {
+        parts.push(&rest[..end]);
+        rest = &rest[end + separator.len_utf8()..];
+    }
+    parts.push(rest);
+    parts
+}
+
+fn line_at(source: &str, offset: usize) -> usize {
+    source[..offset.min(source.len())].matches('\n').count() + 1
+}
+
+/// Parse a comma-separated selector list.
+pub(super) fn parse_selector_list(text: &str) -> Result<Vec<Selector>, String> {
+    text.split(',').map(|s| parse_selector(s.trim())).collect()
+}
+
+fn parse_selector(text: &str) -> Result<Selector, String> {
+    if text.is_empty() {
+        return Err("empty selector".to_string());
+    }
+
+    let spaced = text.replace('>', " > ");
+    let mut parts = Vec::new();
+    let mut combinator = Combinator::Descendant;
+    let mut pending_child = false;
+
+    for token in spaced.split_whitespace() {
+        if token == ">" {
+            if parts.is_empty() || pending_child {
+                return Err(format!("misplaced '>' in selector '{}'", text));
+            }
+            pending_child = true;
+            combinator = Combinator::Child;
+            continue;
+        }
+        parts.push((combinator, parse_compound(token)?));
+        combinator = Combinator::Descendant;
+        pending_child = false;
+    }
+
+    if pending_child {
+        return Err(format!("selector '{}' ends with '>'", text));
+    }
+    Ok(Selector { parts })
+}
+
+fn parse_compound(token: &str) -> Result<Compound, String> {
+    let mut compound = Compound::default();
+
+    // Leading kind name or '*'
+    let kind_end = token.find(['#', '.', ':']).unwrap_or(token.len());
+    if kind_end > 0 {
+        compound.kind = Some(parse_kind(&token[..kind_end])?);
+    }
+
+    // Then any number of `#id` / `.class` / `:state` parts
+    let mut rest = &token[kind_end..];
+    while let Some(prefix) = rest.chars().next() {
+        let body = &rest[1..];
+        let end = body.find(['#', '.', ':']).unwrap_or(body.len());
+        let name = &body[..end];
+        if !is_ident(name) {
+            return Err(format!("invalid name '{}' in selector '{}'", name, token));
+        }
+        match prefix {
+            '#' if compound.id.is_some() => {
+                return Err(format!("multiple ids in selector '{}'", token));
+            }
+            '#' => compound.id = Some(name.to_string()),
+            ':' => compound.states.push(parse_state(name)?),
+            _ => compound.classes.push(name.to_string()),
+        }
+        rest = &body[end..];
+    }
+
+    Ok(compound)
+}
+
+fn parse_kind(name: &str) -> Result<KindSelector, String> {
+    Ok(match name {
+        "*" => KindSelector::Any,
+        "container" => KindSelector::Container,
+        _ => KindSelector::Widget(
+            WidgetKind::from_name(name).ok_or_else(|| format!("unknown widget kind '{}'", name))?,
+        ),
+    })
+}
+
+fn parse_state(name: &str) -> Result<NodeState, String> {
+    Ok(match name {
+        "focus" => NodeState::Focus,
+        "hover" => NodeState::Hover,
+        "active" => NodeState::Active,
+        "disabled" => NodeState::Disabled,
+        _ => return Err(format!("unknown pseudo-class ':{}'", name)),
+    })
+}
+
+fn is_ident(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Parse one `property: value` pair.
+fn parse_declaration(name: &str, value: &str) -> Result<Declaration, String> {
+    let invalid = || format!("invalid value '{}' for '{}'", value, name);
+
+    Ok(match name {
+        "flex-direction" | "direction" => Declaration::FlexDirection(match value {
+            "row" => FlexDirection::Row,
+            "column" => FlexDirection::Column,
+            _ => return Err(invalid()),
+        }),
+        "gap" => Declaration::Gap(parse_u16(value).ok_or_else(invalid)?),
+        "padding" => Declaration::Padding(parse_offset(value).ok_or_else(invalid)?),
+        "border" => Declaration::Border(parse_border(value).ok_or_else(invalid)?),
+        "border-sides" => Declaration::BorderSides(parse_sides(value).ok_or_else(invalid)?),
+        "border-color" => Declaration::BorderColor(parse_color(value).ok_or_else(invalid)?),
+        "border-focus-color" => {
+            Declaration::BorderFocusColor(parse_color(value).ok_or_else(invalid)?)
+        }
+        "title" => Declaration::Title(parse_text(value)),
+        "title-align" => Declaration::TitleAlign(parse_align(value).ok_or_else(invalid)?),
+        "footer" => Declaration::Footer(parse_text(value)),
+        "footer-align" => Declaration::FooterAlign(parse_align(value).ok_or_else(invalid)?),
+        "layout" => Declaration::LayoutMode(match value {
+            "tiled" => LayoutMode::Tiled,
+            "auto" => LayoutMode::Auto,
+            _ => return Err(invalid()),
+        }),
+        "position" => Declaration::PositionMode(match value {
+            "normal" => PositionMode::Normal,
+            "floating" => PositionMode::Floating,
+            _ => return Err(invalid()),
+        }),
+        "x" => Declaration::X(parse_u16(value).ok_or_else(invalid)?),
+        "y" => Declaration::Y(parse_u16(value).ok_or_else(invalid)?),
+        "width" => Declaration::Width(parse_u16(value).ok_or_else(invalid)?),
+        "height" => Declaration::Height(parse_u16(value).ok_or_else(invalid)?),
+        "background" | "bg" => Declaration::BgColor(parse_color(value).ok_or_else(invalid)?),
+        "color" | "fg" => Declaration::FgColor(parse_color(value).ok_or_else(invalid)?),
+        "z-index" => Declaration::ZIndex(value.parse().map_err(|_| invalid())?),
+        "overflow" => Declaration::Overflow(match value {
+            "visible" => Overflow::Visible,
+            "hidden" => Overflow::Hidden,
+            "scroll" => Overflow::Scroll,
+            "auto" => Overflow::Auto,
+            _ => return Err(invalid()),
+        }),
+        "visibility" => Declaration::Visible(match value {
+            "visible" => true,
+            "hidden" => false,
+            _ => return Err(invalid()),
+        }),
+        "display" => Declaration::Display(match value {
+            "flex" | "block" => Display::Flex,
+            "none" => Display::None,
+            _ => return Err(invalid()),
+        }),
+        "disabled" => Declaration::Disabled(parse_bool(value).ok_or_else(invalid)?),
+        "focusable" => Declaration::Focusable(parse_bool(value).ok_or_else(invalid)?),
+        "tab-index" => Declaration::TabIndex(value.parse().map_err(|_| invalid())?),
+        "modal" => Declaration::Modal(parse_bool(value).ok_or_else(invalid)?),
+        _ => return Err(format!("unknown property '{}'", name)),
+    })
+}
+
+fn parse_bool(value: &str) -> Option<bool> {
+    match value {
+        "true" | "yes" => Some(true),
+        "false" | "no" => Some(false),
+        _ => None,
+    }
+}
+
+fn parse_u16(value: &str) -> Option<u16> {
+    value.trim_end_matches("px").parse().ok()
+}
+
+/// `1`, `1 2` (vertical horizontal) or `1 2 3 4` (top right bottom left).
+fn parse_offset(value: &str) -> Option<RectOffset> {
+    let values: Vec<u16> = value
+        .split_whitespace()
+        .map(parse_u16)
+        .collect::<Option<_>>()?;
+    match values[..] {
+        [all] => Some(RectOffset::all(all)),
+        [v, h] => Some(RectOffset::new(v, h, v, h)),
+        [t, r, b, l] => Some(RectOffset::new(t, r, b, l)),
+        _ => None,
+    }
+}
+
+fn parse_border(value: &str) -> Option<Option<BorderType>> {
+    Some(match value {
+        "none" => None,
+        "plain" => Some(BorderType::Plain),
+        "rounded" => Some(BorderType::Rounded),
+        "double" => Some(BorderType::Double),
+        "thick" => Some(BorderType::Thick),
+        _ => return None,
+    })
+}
+
+/// `all`, `none`, or a space-separated list of `top`/`right`/`bottom`/`left`.
+fn parse_sides(value: &str) -> Option<BorderSides> {
+    match value {
+        "all" => return Some(BorderSides::ALL),
+        "none" => return Some(BorderSides::NONE),
+        _ => {}
+    }
+    value
+        .split_whitespace()
+        .try_fold(BorderSides::NONE, |sides, side| {
+            Some(
+                sides
+                    | match side {
+                        "top" => BorderSides::TOP,
+                        "right" => BorderSides::RIGHT,
+                        "bottom" => BorderSides::BOTTOM,
+                        "left" => BorderSides::LEFT,
+                        _ => return None,
+                    },
+            )
+        })
+}
+
+fn parse_align(value: &str) -> Option<TextAlign> {
+    match value {
+        "left" => Some(TextAlign::Left),
+        "center" => Some(TextAlign::Center),
+        "right" => Some(TextAlign::Right),
+        _ => None,
+    }
+}
+
+/// Quoted or bare text; `none` clears it.
+fn parse_text(value: &str) -> Option<String> {
+    if value == "none" {
+        return None;
+    }
+    let unquoted = value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))
+        .unwrap_or(value);
+    Some(unquoted.to_string())
+}
+
+/// Named colors, `#rgb`, `#rrggbb`, `rgb(r, g, b)`, theme slots, or `none`.
+fn parse_color(value: &str) -> Option<Option<Color>> {
+    let color = match value {
+        "none" | "transparent" => return Some(None),
+        "reset" | "default" => Color::Reset,
+        "black" => Color::Black,
+        "red" => Color::Red,
+        "green" => Color::Green,
+        "yellow" => Color::Yellow,
+        "blue" => Color::Blue,
+        "magenta" => Color::Magenta,
+        "cyan" => Color::Cyan,
+        "white" => Color::White,
+        _ => {
+            if let Some(slot) = ThemeColor::from_name(value) {
+                Color::Theme(slot)
+            } else if let Some(hex) = value.strip_prefix('#') {
+                parse_hex(hex)?
+            } else if let Some(args) = value
+                .strip_prefix("rgb(")
+                .and_then(|rest| rest.strip_suffix(')'))
+            {
+                let channels: Vec<u8> = args
+                    .split(',')
+                    .map(|c| c.trim().parse().ok())
+                    .collect::<Option<_>>()?;
+                match channels[..] {
+                    [r, g, b] => Color::Rgb(r, g, b),
+                    _ => return None,
+                }
+            } else {
+                return None;
+            }
+        }
+    };
+    Some(Some(color))
+}
+
+fn parse_hex(hex: &str) -> Option<Color> {
+    if !hex.is_ascii() {
+        return None;
+    }
+    let channel = |s: &str| u8::from_str_radix(s, 16).ok();
+    match hex.len() {
+        3 => {
+            let expand = |i: usize| channel(&hex[i..i + 1]).map(|v| v * 17);
+            Some(Color::Rgb(expand(0)?, expand(1)?, expand(2)?))
+        }
+        6 => Some(Color::Rgb(
+            channel(&hex[0..2])?,
+            channel(&hex[2..4])?,
+            channel(&hex[4..6])?,
+        )),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Style;
+    use crate::stylesheet::{SelectorTarget, Stylesheet};
+
+    fn target(id: &str, kind: Option<WidgetKind>, classes: &[&str]) -> SelectorTarget {
+        SelectorTarget {
+            id: id.to_string(),
+            kind,
+            classes: classes.iter().map(|c| c.to_string()).collect(),
+            states: Vec::new(),
+        }
+    }
+
+    fn width_for(sheet: &str, path: &[SelectorTarget]) -> u16 {
+        let mut style = Style::new();
+        Stylesheet::parse(sheet).unwrap().apply(path, &mut style);
+        style.width
+    }
+
+    #[test]
+    fn parses_compound_selectors() {
+        let selectors = parse_selector_list("input#name.wide.dark:focus").unwrap();
+        let compound = &selectors[0].parts[0].1;
+        assert_eq!(compound.kind, Some(KindSelector::Widget(WidgetKind::Input)));
+        assert_eq!(compound.id.as_deref(), Some("name"));
+        assert_eq!(compound.classes, ["wide", "dark"]);
+        assert_eq!(compound.states, [NodeState::Focus]);
+    }
+
+    #[test]
+    fn parses_combinators_and_lists() {
+        let selectors = parse_selector_list("#sidebar > text, .panel *").unwrap();
+        assert_eq!(selectors.len(), 2);
+        assert_eq!(selectors[0].parts[1].0, Combinator::Child);
+        assert_eq!(selectors[1].parts[1].0, Combinator::Descendant);
+        assert_eq!(selectors[1].parts[1].1.kind, Some(KindSelector::Any));
+
+        // A rule with a selector list becomes one rule per selector
+        let rules = parse_rules("text, .b { width: 1; }").unwrap();
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].declarations, rules[1].declarations);
+    }
+
+    #[test]
+    fn matches_child_and_descendant() {
+        let path = [
+            target("root", None, &[]),
+            target("sidebar", None, &["panel"]),
+            target("inner", None, &[]),
+            target("label", Some(WidgetKind::Text), &[]),
+        ];
+        assert_eq!(width_for(".panel text { width: 5; }", &path), 5);
+        assert_eq!(width_for(".panel > text { width: 5; }", &path), 0);
+        assert_eq!(width_for("#inner > text { width: 5; }", &path), 5);
+    }
+
+    #[test]
+    fn specificity_orders_rules() {
+        let a = parse_selector_list("#a").unwrap().remove(0);
+        let b = parse_selector_list("text.b:hover").unwrap().remove(0);
+        let c = parse_selector_list("container text").unwrap().remove(0);
+        assert_eq!(a.specificity(), (1, 0, 0));
+        assert_eq!(b.specificity(), (0, 2, 1));
+        assert_eq!(c.specificity(), (0, 0, 2));
+
+        let path = [target("a", Some(WidgetKind::Text), &["b"])];
+        // The more specific rule wins regardless of source order
+        assert_eq!(
+            width_for(
+                "#a { width: 1; } .b { width: 2; } text { width: 3; }",
+                &path
+            ),
+            1
+        );
+        // Equal specificity: the later rule wins
+        assert_eq!(width_for(".b { width: 1; } .b { width: 2; }", &path), 2);
+    }
+
+    #[test]
+    fn quoted_values_keep_separators() {
+        let rules =
+            parse_rules(r#".a { title: "a; b"; footer: 'x } /* y */'; width: 3; }"#).unwrap();
+        assert_eq!(
+            rules[0].declarations,
+            [
+                Declaration::Title(Some("a; b".to_string())),
+                Declaration::Footer(Some("x } /* y */".to_string())),
+                Declaration::Width(3),
+            ]
+        );
+    }
+
+    #[test]
+    fn comments_are_ignored() {
+        let rules = parse_rules("/* a { } */ .a { /* ; */ width: 1; }").unwrap();
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].declarations, [Declaration::Width(1)]);
+    }
+
+    #[test]
+    fn parses_values() {
+        let rules = parse_rules(
+            ".a { padding: 1 2; border: rounded; color: #f80; bg: rgb(1, 2, 3); border-color: primary; }",
+        )
+        .unwrap();
+        assert_eq!(
+            rules[0].declarations,
+            [
+                Declaration::Padding(RectOffset::new(1, 2, 1, 2)),
+                Declaration::Border(Some(BorderType::Rounded)),
+                Declaration::FgColor(Some(Color::Rgb(255, 136, 0))),
+                Declaration::BgColor(Some(Color::Rgb(1, 2, 3))),
+                Declaration::BorderColor(Some(Color::Theme(ThemeColor::Primary))),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_errors_with_lines() {
+        let error = |source: &str| parse_rules(source).unwrap_err();
+
+        let e = error(".a {\n  width: 1;\n  wdth: 2;\n}");
+        assert_eq!(e.line, 3);
+        assert_eq!(e.message, "unknown property 'wdth'");
+
+        assert_eq!(
+            error(".a { width: wide; }").message,
+            "invalid value 'wide' for 'width'"
+        );
+        assert_eq!(error(".a { width 1; }").line, 1);
+        assert_eq!(error("\n.a { width: 1;").message, "missing closing '}'");
+        assert_eq!(error(".a { title: \"x; }").message, "missing closing '}'");
+        assert_eq!(error("widget { }").message, "unknown widget kind 'widget'");
+        assert_eq!(error(".a > { }").message, "selector '.a >' ends with '>'");
+        assert_eq!(
+            error("> .a { }").message,
+            "misplaced '>' in selector '> .a'"
+        );
+        assert_eq!(
+            error(".a:visited { }").message,
+            "unknown pseudo-class ':visited'"
+        );
+        assert_eq!(error("#a#b { }").message, "multiple ids in selector '#a#b'");
+        assert_eq!(error(", .a { }").message, "empty selector");
+
+        let e = error(".a { }\n\n  stray");
+        assert_eq!(e.line, 3);
+        assert_eq!(e.message, "unexpected text 'stray'");
+    }
+}
@@ -0,0 +1,118 @@
+use crate::style::NodeState;
+use crate::widget::WidgetKind;
+
+/// Information about a node needed for selector matching.
+#[derive(Debug, Clone)]
+pub struct SelectorTarget {
+    pub id: String,
+    /// `None` for container nodes.
+    pub kind: Option<WidgetKind>,
+    pub classes: Vec<String>,
+    /// Interaction states the node is currently in.
+    pub states: Vec<NodeState>,
+}
+
+/// Node type part of a compound selector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KindSelector {
+    /// `*` - any node.
+    Any,
+    /// `container` - nodes without a widget.
+    Container,
+    /// `input`, `text`, ... - nodes with the given widget kind.
+    Widget(WidgetKind),
+}
+
+/// A compound selector like `input#name.wide:focus`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Compound {
+    pub kind: Option<KindSelector>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+    /// Pseudo-classes (`:focus`, `:hover`, ...).
+    pub states: Vec<NodeState>,
+}
+
+impl Compound {
+    fn matches(&self, target: &SelectorTarget) -> bool {
+        let kind_ok = match self.kind {
+            None | Some(KindSelector::Any) => true,
+            Some(KindSelector::Container) => target.kind.is_none(),
+            Some(KindSelector::Widget(kind)) => target.kind == Some(kind),
+        };
+        kind_ok
+            && self.id.as_ref().is_none_or(|id| *id == target.id)
+            && self.classes.iter().all(|c| target.classes.contains(c))
+            && self.states.iter().all(|s| target.states.contains(s))
+    }
+}
+
+/// How a compound relates to the one before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Combinator {
+    /// `a b` - b is anywhere below a.
+    Descendant,
+    /// `a > b` - b is a direct child of a.
+    Child,
+}
+
+/// A complex selector: compounds joined by combinators.
+///
+/// The first compound's combinator is ignored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selector {
+    pub parts: Vec<(Combinator, Compound)>,
+}
+
+impl Selector {
+    /// Specificity as (ids, classes and pseudo-classes, kinds), compared lexicographically.
+    pub fn specificity(&self) -> (u16, u16, u16) {
+        self.parts
+            .iter()
+            .fold((0, 0, 0), |(ids, classes, kinds), (_, compound)| {
+                (
+                    ids + compound.id.is_some() as u16,
+                    classes + (compound.classes.len() + compound.states.len()) as u16,
+                    kinds
+                        + matches!(
+                            compound.kind,
+                            Some(KindSelector::Container | KindSelector::Widget(_))
+                        ) as u16,
+                )
+            })
+    }
+
+    /// Check whether the last node of `path` (root first) matches this selector.
+    pub fn matches(&self, path: &[SelectorTarget]) -> bool {
+        match path.split_last() {
+            Some((target, ancestors)) => Self::match_from(&self.parts, target, ancestors),
+            None => false,
+        }
+    }
+
+    fn match_from(
+        parts: &[(Combinator, Compound)],
+        target: &SelectorTarget,
+        ancestors: &[SelectorTarget],
+    ) -> bool {
+        let Some(((combinator, compound), rest)) = parts.split_last() else {
+            return true;
+        };
+        if !compound.matches(target) {
+            return false;
+        }
+        if rest.is_empty() {
+            return true;
+        }
+
+        match combinator {
+            Combinator::Child => match ancestors.split_last() {
+                Some((parent, above)) => Self::match_from(rest, parent, above),
+                None => false,
+            },
+            Combinator::Descendant => (0..ancestors.len())
+                .rev()
+                .any(|i| Self::match_from(rest, &ancestors[i], &ancestors[..i])),
+        }
+    }
+}
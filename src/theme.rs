@@ -0,0 +1,178 @@
+use crate::style::Color;
+
+/// Semantic color slot in a [`Theme`].
+///
+/// Use `Color::Theme(ThemeColor::Primary)` in a `Style` to reference the
+/// active theme instead of a fixed color. The render loop resolves it at draw time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ThemeColor {
+    Primary,
+    Surface,
+    Border,
+    Error,
+    Warning,
+    Success,
+    Focus,
+    Selection,
+    Text,
+    Muted,
+}
+
+impl ThemeColor {
+    /// Parse a slot name such as `"primary"` or `"muted"`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "primary" => ThemeColor::Primary,
+            "surface" => ThemeColor::Surface,
+            "border" => ThemeColor::Border,
+            "error" => ThemeColor::Error,
+            "warning" => ThemeColor::Warning,
+            "success" => ThemeColor::Success,
+            "focus" => ThemeColor::Focus,
+            "selection" => ThemeColor::Selection,
+            "text" => ThemeColor::Text,
+            "muted" => ThemeColor::Muted,
+            _ => return None,
+        })
+    }
+}
+
+/// A named palette of semantic colors.
+///
+/// Set it with `Document::set_theme`. Switching themes re-renders every node
+/// that references a `ThemeColor`, without touching the nodes themselves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Theme {
+    pub name: String,
+    pub primary: Color,
+    pub surface: Color,
+    pub border: Color,
+    pub error: Color,
+    pub warning: Color,
+    pub success: Color,
+    pub focus: Color,
+    pub selection: Color,
+    pub text: Color,
+    pub muted: Color,
+}
+
+impl Theme {
+    /// Get the concrete color for a semantic slot.
+    pub fn get(&self, color: ThemeColor) -> Color {
+        match color {
+            ThemeColor::Primary => self.primary,
+            ThemeColor::Surface => self.surface,
+            ThemeColor::Border => self.border,
+            ThemeColor::Error => self.error,
+            ThemeColor::Warning => self.warning,
+            ThemeColor::Success => self.success,
+            ThemeColor::Focus => self.focus,
+            ThemeColor::Selection => self.selection,
+            ThemeColor::Text => self.text,
+            ThemeColor::Muted => self.muted,
+        }
+    }
+
+    /// Neutral dark theme (default).
+    pub fn dark() -> Self {
+        Theme {
+            name: "dark".to_string(),
+            primary: Color::Rgb(97, 175, 239),
+            surface: Color::Rgb(30, 33, 39),
+            border: Color::Rgb(92, 99, 112),
+            error: Color::Rgb(224, 108, 117),
+            warning: Color::Rgb(229, 192, 123),
+            success: Color::Rgb(152, 195, 121),
+            focus: Color::Rgb(198, 120, 221),
+            selection: Color::Rgb(62, 68, 81),
+            text: Color::Rgb(220, 223, 228),
+            muted: Color::Rgb(127, 132, 142),
+        }
+    }
+
+    /// Neutral light theme.
+    pub fn light() -> Self {
+        Theme {
+            name: "light".to_string(),
+            primary: Color::Rgb(64, 120, 242),
+            surface: Color::Rgb(250, 250, 250),
+            border: Color::Rgb(160, 161, 167),
+            error: Color::Rgb(228, 86, 73),
+            warning: Color::Rgb(193, 132, 1),
+            success: Color::Rgb(80, 161, 79),
+            focus: Color::Rgb(166, 38, 164),
+            selection: Color::Rgb(229, 229, 230),
+            text: Color::Rgb(56, 58, 66),
+            muted: Color::Rgb(130, 131, 137),
+        }
+    }
+
+    /// Nord (dark).
+    pub fn nord() -> Self {
+        Theme {
+            name: "nord".to_string(),
+            primary: Color::Rgb(136, 192, 208),
+            surface: Color::Rgb(46, 52, 64),
+            border: Color::Rgb(76, 86, 106),
+            error: Color::Rgb(191, 97, 106),
+            warning: Color::Rgb(235, 203, 139),
+            success: Color::Rgb(163, 190, 140),
+            focus: Color::Rgb(129, 161, 193),
+            selection: Color::Rgb(67, 76, 94),
+            text: Color::Rgb(236, 239, 244),
+            muted: Color::Rgb(216, 222, 233),
+        }
+    }
+
+    /// Gruvbox (dark).
+    pub fn gruvbox() -> Self {
+        Theme {
+            name: "gruvbox".to_string(),
+            primary: Color::Rgb(131, 165, 152),
+            surface: Color::Rgb(40, 40, 40),
+            border: Color::Rgb(102, 92, 84),
+            error: Color::Rgb(251, 73, 52),
+            warning: Color::Rgb(250, 189, 47),
+            success: Color::Rgb(184, 187, 38),
+            focus: Color::Rgb(254, 128, 25),
+            selection: Color::Rgb(80, 73, 69),
+            text: Color::Rgb(235, 219, 178),
+            muted: Color::Rgb(168, 153, 132),
+        }
+    }
+
+    /// Solarized (light).
+    pub fn solarized_light() -> Self {
+        Theme {
+            name: "solarized-light".to_string(),
+            primary: Color::Rgb(38, 139, 210),
+            surface: Color::Rgb(253, 246, 227),
+            border: Color::Rgb(147, 161, 161),
+            error: Color::Rgb(220, 50, 47),
+            warning: Color::Rgb(181, 137, 0),
+            success: Color::Rgb(133, 153, 0),
+            focus: Color::Rgb(108, 113, 196),
+            selection: Color::Rgb(238, 232, 213),
+            text: Color::Rgb(101, 123, 131),
+            muted: Color::Rgb(147, 161, 161),
+        }
+    }
+
+    /// Look up a built-in theme by name.
+    pub fn by_name(name: &str) -> Option<Self> {
+        match name {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            "nord" => Some(Self::nord()),
+            "gruvbox" => Some(Self::gruvbox()),
+            "solarized-light" => Some(Self::solarized_light()),
+            _ => None,
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
@@ -0,0 +1,82 @@
+//! Timers and animation frames run by the render loop.
+//!
+//! Callbacks run inside the render loop, before layout, so UI changes they
+//! queue through `ctx.ui()` show up in the very next frame.
+//!
+//! ```no_run
+//! # use std::time::Duration;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let doc = ccui::Ui::run()?;
+//! let frames = ["|", "/", "-", "\\"];
+//! let mut i = 0;
+//! doc.set_interval(Duration::from_millis(100), move |ctx| {
+//!     i = (i + 1) % frames.len();
+//!     ctx.ui().set_content("spinner", frames[i]);
+//! })?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::commands::UiCommands;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// Unique identifier for a timer, interval or animation frame request.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TimerId(u64);
+
+impl TimerId {
+    pub fn new() -> Self {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        TimerId(COUNTER.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+impl Default for TimerId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// When a timer fires.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimerKind {
+    /// Once, after the delay.
+    Timeout(Duration),
+    /// Repeatedly, every period, until cleared.
+    Interval(Duration),
+    /// Once, on the next frame.
+    AnimationFrame,
+}
+
+/// Context passed to timer callbacks.
+pub struct TimerContext {
+    pub timer_id: TimerId,
+    /// When this batch of callbacks started; all frame callbacks of one frame
+    /// see the same time.
+    pub now: Instant,
+    pub(crate) ui: UiCommands,
+}
+
+impl TimerContext {
+    /// Queue UI changes to apply before the next frame is drawn.
+    pub fn ui(&self) -> &UiCommands {
+        &self.ui
+    }
+}
+
+/// Timer callback type.
+pub type TimerCallback = Box<dyn FnMut(&TimerContext) + Send + 'static>;
+
+/// Wrap a callback that only ever runs once.
+pub(crate) fn once<F>(callback: F) -> TimerCallback
+where
+    F: FnOnce(&TimerContext) + Send + 'static,
+{
+    let mut callback = Some(callback);
+    Box::new(move |ctx| {
+        if let Some(callback) = callback.take() {
+            callback(ctx);
+        }
+    })
+}
@@ -7,8 +7,9 @@ use std::sync::Arc;
 ///
 /// # Example
 /// ```rust
-/// use ccui::Shared;
+/// use ccui::{Document, EventType, Shared};
 ///
+/// # fn setup(doc: &Document) -> Result<(), Box<dyn std::error::Error>> {
 /// let counter = Shared::new(0);
 ///
 /// // Clone reference (not cloning data)
@@ -20,6 +21,8 @@ use std::sync::Arc;
 ///         counter.with(|c| *c += 1);
 ///     }
 /// })?;
+/// # Ok(())
+/// # }
 /// ```
 pub struct Shared<T>(Arc<Mutex<T>>);
 
@@ -40,7 +43,9 @@ impl<T> Shared<T> {
     ///
     /// # Example
     /// ```rust
+    /// # let counter = ccui::Shared::new(0);
     /// counter.with(|c| *c += 1);
+    /// # assert_eq!(counter.read(|c| *c), 1);
     /// ```
     pub fn with<F, R>(&self, f: F) -> R
     where
@@ -53,7 +58,9 @@ impl<T> Shared<T> {
     ///
     /// # Example
     /// ```rust
+    /// # let counter = ccui::Shared::new(0);
     /// let value = counter.read(|c| *c);
+    /// # assert_eq!(value, 0);
     /// ```
     pub fn read<F, R>(&self, f: F) -> R
     where
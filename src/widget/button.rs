@@ -0,0 +1,276 @@
+use crate::event::{EventContext, EventStream, EventType, UiMessage, WidgetEvent, WidgetMessage};
+use crate::style::{BorderType, Color, Style};
+use crate::theme::ThemeColor;
+use crate::widget::{Widget, WidgetKind, WidgetType};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use parking_lot::Mutex;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Position, Rect},
+    style::{Modifier, Style as RatatuiStyle},
+    text::Line,
+    widgets::{Block, Paragraph, Widget as RatatuiWidget},
+};
+use std::any::Any;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use unicode_width::UnicodeWidthStr;
+
+/// Messages for Button widget.
+pub enum ButtonMessage {
+    SetLabel(String),
+}
+
+impl WidgetMessage for ButtonMessage {
+    fn apply(self: Box<Self>, widget: &mut dyn Widget) {
+        if let Some(button) = widget.as_any_mut().downcast_mut::<Button>() {
+            match *self {
+                ButtonMessage::SetLabel(label) => *button.label.lock() = label,
+            }
+        }
+    }
+}
+
+/// Events from a Button widget, see `ButtonHandle::events`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ButtonEvent {
+    Pressed,
+    Focused,
+    Blurred,
+}
+
+impl ButtonEvent {
+    fn from_ctx(ctx: &EventContext) -> Option<Self> {
+        Some(match ctx.event_type {
+            EventType::Press => ButtonEvent::Pressed,
+            EventType::Focus => ButtonEvent::Focused,
+            EventType::Blur => ButtonEvent::Blurred,
+            _ => return None,
+        })
+    }
+}
+
+/// Handle for controlling a Button widget.
+#[derive(Clone)]
+pub struct ButtonHandle {
+    id: String,
+    style: crate::style::Style,
+    ui_tx: mpsc::Sender<UiMessage>,
+    label: Arc<Mutex<String>>,
+}
+
+impl crate::document::WidgetHandle for ButtonHandle {
+    fn id(&self) -> &str {
+        &self.id
+    }
+    fn style(&self) -> &crate::style::Style {
+        &self.style
+    }
+    fn ui_tx(&self) -> &mpsc::Sender<UiMessage> {
+        &self.ui_tx
+    }
+}
+
+impl ButtonHandle {
+    /// Get the current label.
+    pub fn get_label(&self) -> String {
+        self.label.lock().clone()
+    }
+
+    /// Set the label.
+    pub fn set_label(
+        &self,
+        label: impl Into<String>,
+    ) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        let label = label.into();
+        *self.label.lock() = label.clone();
+        self.ui_tx.try_send(UiMessage::WidgetMessage {
+            id: self.id.clone(),
+            message: Box::new(ButtonMessage::SetLabel(label)),
+        })?;
+        Ok(())
+    }
+
+    /// Enable or disable the button. Disabled buttons can't be focused or pressed.
+    pub fn set_enabled(&self, enabled: bool) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        crate::document::WidgetHandle::set_disabled(self, !enabled)
+    }
+
+    /// Stream of this button's press and focus events.
+    pub fn events(&self) -> Result<EventStream<ButtonEvent>, mpsc::error::TrySendError<UiMessage>> {
+        EventStream::subscribe(
+            &self.id,
+            &self.ui_tx,
+            &[EventType::Press, EventType::Focus, EventType::Blur],
+            ButtonEvent::from_ctx,
+        )
+    }
+}
+
+impl WidgetType for Button {
+    type Handle = ButtonHandle;
+
+    fn kind() -> WidgetKind {
+        WidgetKind::Button
+    }
+
+    fn create_handle(
+        &self,
+        id: String,
+        ui_tx: mpsc::Sender<UiMessage>,
+        style: crate::style::Style,
+    ) -> Self::Handle {
+        ButtonHandle {
+            id,
+            style,
+            ui_tx,
+            label: Arc::clone(&self.label),
+        }
+    }
+}
+
+/// A clickable button that emits `EventType::Press` on Enter, Space or a
+/// click. Clicks press on release, so moving off the button first cancels.
+pub struct Button {
+    label: Arc<Mutex<String>>,
+    border_type: Option<BorderType>,
+    /// Initial `disabled` style; afterwards the node's style is what counts.
+    disabled: bool,
+    /// Events raised by input, until taken by the render loop.
+    events: Vec<WidgetEvent>,
+}
+
+impl Button {
+    pub fn new<S: Into<String>>(label: S) -> Self {
+        Button {
+            label: Arc::new(Mutex::new(label.into())),
+            border_type: None,
+            disabled: false,
+            events: Vec::new(),
+        }
+    }
+
+    /// Create a button with border.
+    pub fn bordered(mut self, border_type: BorderType) -> Self {
+        self.border_type = Some(border_type);
+        self
+    }
+
+    /// Start disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn label(&self) -> String {
+        self.label.lock().clone()
+    }
+
+    pub fn set_label<S: Into<String>>(&mut self, label: S) {
+        *self.label.lock() = label.into();
+    }
+
+    fn press(&mut self) {
+        self.events.push(WidgetEvent::Press);
+    }
+}
+
+impl Default for Button {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+impl Widget for Button {
+    fn render(&self, buffer: &mut Buffer, area: Rect, style: &Style, is_focused: bool) {
+        // Apply padding
+        let inner_area = style.shrink(area);
+
+        let label = self.label.lock();
+        let block = if self.border_type.is_some() {
+            let mut block = Block::default()
+                .borders(style.border_sides.into())
+                .border_type(match self.border_type {
+                    Some(BorderType::Plain) => ratatui::widgets::BorderType::Plain,
+                    Some(BorderType::Rounded) => ratatui::widgets::BorderType::Rounded,
+                    Some(BorderType::Double) => ratatui::widgets::BorderType::Double,
+                    Some(BorderType::Thick) => ratatui::widgets::BorderType::Thick,
+                    None => ratatui::widgets::BorderType::Plain,
+                });
+            if let Some(color) = style.border_color_for(is_focused) {
+                block = block.border_style(RatatuiStyle::default().fg(color.into()));
+            }
+            block
+        } else {
+            Block::default()
+        };
+        let label_area = block.inner(inner_area);
+        block.render(inner_area, buffer);
+
+        // Borderless buttons are drawn in brackets
+        let text = if self.border_type.is_some() {
+            format!(" {} ", label)
+        } else {
+            format!("[ {} ]", label)
+        };
+        let mut text_style = RatatuiStyle::default();
+        if style.disabled {
+            text_style = text_style.add_modifier(Modifier::DIM);
+        } else if is_focused {
+            text_style = text_style.add_modifier(Modifier::REVERSED);
+        }
+
+        // Center the label vertically
+        let y = label_area.y + label_area.height.saturating_sub(1) / 2;
+        let line_area = Rect::new(label_area.x, y, label_area.width, label_area.height.min(1));
+        Paragraph::new(Line::styled(text, text_style))
+            .alignment(Alignment::Center)
+            .render(line_area, buffer);
+    }
+
+    fn node_style_hint(&self) -> Option<Style> {
+        let style = match self.border_type {
+            Some(border_type) => Style::new().border(border_type),
+            None => Style::new().no_border(),
+        };
+        Some(
+            style
+                .focusable(true)
+                .disabled(self.disabled)
+                .on_active(|s| s.bg_color(Color::Theme(ThemeColor::Selection))),
+        )
+    }
+
+    fn content_size(&self, _area: Rect) -> (u16, u16) {
+        // Label plus brackets or border, and spaces on both sides
+        let width = self.label.lock().width() as u16 + 4;
+        let height = if self.border_type.is_some() { 3 } else { 1 };
+        (width, height)
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let plain = key.modifiers.difference(KeyModifiers::SHIFT).is_empty();
+        if plain && matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
+            self.press();
+            return true;
+        }
+        false
+    }
+
+    fn handle_mouse(&mut self, mouse: MouseEvent, area: Rect, _style: &Style) -> bool {
+        let inside = area.contains(Position::new(mouse.column, mouse.row));
+        if mouse.kind == MouseEventKind::Up(MouseButton::Left) && inside {
+            self.press();
+            return true;
+        }
+        false
+    }
+
+    fn take_events(&mut self) -> Vec<WidgetEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -0,0 +1,335 @@
+use crate::event::{EventContext, EventStream, EventType, UiMessage, WidgetEvent, WidgetMessage};
+use crate::style::Style;
+use crate::widget::{Widget, WidgetKind, WidgetType};
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use parking_lot::Mutex;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style as RatatuiStyle},
+    text::{Line, Span},
+    widgets::{Paragraph, Widget as RatatuiWidget},
+};
+use std::any::Any;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use unicode_width::UnicodeWidthStr;
+
+/// State of a checkbox.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CheckState {
+    #[default]
+    Unchecked,
+    Checked,
+    /// Neither checked nor unchecked, e.g. a "select all" box over a partial selection.
+    Indeterminate,
+}
+
+impl CheckState {
+    /// Value carried by `Change` events: `"true"`, `"false"` or `"indeterminate"`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CheckState::Unchecked => "false",
+            CheckState::Checked => "true",
+            CheckState::Indeterminate => "indeterminate",
+        }
+    }
+
+    /// Parse a `Change` event value.
+    pub fn from_value(value: &str) -> Option<Self> {
+        Some(match value {
+            "false" => CheckState::Unchecked,
+            "true" => CheckState::Checked,
+            "indeterminate" => CheckState::Indeterminate,
+            _ => return None,
+        })
+    }
+}
+
+impl From<bool> for CheckState {
+    fn from(checked: bool) -> Self {
+        if checked {
+            CheckState::Checked
+        } else {
+            CheckState::Unchecked
+        }
+    }
+}
+
+/// Messages for Checkbox widget.
+pub enum CheckboxMessage {
+    SetState(CheckState),
+    SetLabel(String),
+}
+
+impl WidgetMessage for CheckboxMessage {
+    fn apply(self: Box<Self>, widget: &mut dyn Widget) {
+        if let Some(checkbox) = widget.as_any_mut().downcast_mut::<Checkbox>() {
+            match *self {
+                CheckboxMessage::SetState(state) => *checkbox.state.lock() = state,
+                CheckboxMessage::SetLabel(label) => checkbox.label = label,
+            }
+        }
+    }
+}
+
+/// Events from a Checkbox widget, see `CheckboxHandle::events`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckboxEvent {
+    Changed(CheckState),
+    Focused,
+    Blurred,
+}
+
+impl CheckboxEvent {
+    fn from_ctx(ctx: &EventContext) -> Option<Self> {
+        Some(match ctx.event_type {
+            EventType::Change => {
+                CheckboxEvent::Changed(CheckState::from_value(ctx.value.as_deref()?)?)
+            }
+            EventType::Focus => CheckboxEvent::Focused,
+            EventType::Blur => CheckboxEvent::Blurred,
+            _ => return None,
+        })
+    }
+}
+
+/// Handle for controlling a Checkbox widget.
+#[derive(Clone)]
+pub struct CheckboxHandle {
+    id: String,
+    style: crate::style::Style,
+    ui_tx: mpsc::Sender<UiMessage>,
+    state: Arc<Mutex<CheckState>>,
+}
+
+impl crate::document::WidgetHandle for CheckboxHandle {
+    fn id(&self) -> &str {
+        &self.id
+    }
+    fn style(&self) -> &crate::style::Style {
+        &self.style
+    }
+    fn ui_tx(&self) -> &mpsc::Sender<UiMessage> {
+        &self.ui_tx
+    }
+}
+
+impl CheckboxHandle {
+    /// Whether the box is checked (indeterminate counts as unchecked).
+    pub fn is_checked(&self) -> bool {
+        *self.state.lock() == CheckState::Checked
+    }
+
+    /// Get the current state.
+    pub fn state(&self) -> CheckState {
+        *self.state.lock()
+    }
+
+    /// Check or uncheck the box. Doesn't fire `Change`.
+    pub fn set_checked(&self, checked: bool) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.set_state(checked.into())
+    }
+
+    /// Set the state, including `Indeterminate`. Doesn't fire `Change`.
+    pub fn set_state(&self, state: CheckState) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        *self.state.lock() = state;
+        self.ui_tx.try_send(UiMessage::WidgetMessage {
+            id: self.id.clone(),
+            message: Box::new(CheckboxMessage::SetState(state)),
+        })?;
+        Ok(())
+    }
+
+    /// Set the label.
+    pub fn set_label(
+        &self,
+        label: impl Into<String>,
+    ) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.ui_tx.try_send(UiMessage::WidgetMessage {
+            id: self.id.clone(),
+            message: Box::new(CheckboxMessage::SetLabel(label.into())),
+        })?;
+        Ok(())
+    }
+
+    /// Stream of this checkbox's change and focus events.
+    pub fn events(
+        &self,
+    ) -> Result<EventStream<CheckboxEvent>, mpsc::error::TrySendError<UiMessage>> {
+        EventStream::subscribe(
+            &self.id,
+            &self.ui_tx,
+            &[EventType::Change, EventType::Focus, EventType::Blur],
+            CheckboxEvent::from_ctx,
+        )
+    }
+}
+
+impl WidgetType for Checkbox {
+    type Handle = CheckboxHandle;
+
+    fn kind() -> WidgetKind {
+        WidgetKind::Checkbox
+    }
+
+    fn create_handle(
+        &self,
+        id: String,
+        ui_tx: mpsc::Sender<UiMessage>,
+        style: crate::style::Style,
+    ) -> Self::Handle {
+        CheckboxHandle {
+            id,
+            style,
+            ui_tx,
+            state: Arc::clone(&self.state),
+        }
+    }
+}
+
+/// A labelled checkbox, toggled with Space, Enter or a click.
+///
+/// Renders as `[x] label`, `[ ] label`, or `[-] label` when indeterminate.
+/// With `tri_state(true)` the user cycles through all three states;
+/// otherwise `Indeterminate` can only be set from code and toggles to checked.
+pub struct Checkbox {
+    label: String,
+    state: Arc<Mutex<CheckState>>,
+    tri_state: bool,
+    disabled: bool,
+    /// Events raised by input, until taken by the render loop.
+    events: Vec<WidgetEvent>,
+}
+
+impl Checkbox {
+    pub fn new<S: Into<String>>(label: S) -> Self {
+        Checkbox {
+            label: label.into(),
+            state: Arc::new(Mutex::new(CheckState::Unchecked)),
+            tri_state: false,
+            disabled: false,
+            events: Vec::new(),
+        }
+    }
+
+    /// Start checked or unchecked.
+    pub fn checked(self, checked: bool) -> Self {
+        self.state(checked.into())
+    }
+
+    /// Start in the given state.
+    pub fn state(self, state: CheckState) -> Self {
+        *self.state.lock() = state;
+        self
+    }
+
+    /// Let the user cycle unchecked -> checked -> indeterminate.
+    pub fn tri_state(mut self, tri_state: bool) -> Self {
+        self.tri_state = tri_state;
+        self
+    }
+
+    /// Start disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn is_checked(&self) -> bool {
+        *self.state.lock() == CheckState::Checked
+    }
+
+    pub fn get_state(&self) -> CheckState {
+        *self.state.lock()
+    }
+
+    pub fn set_state(&mut self, state: CheckState) {
+        *self.state.lock() = state;
+    }
+
+    fn toggle(&mut self) {
+        let next = {
+            let mut state = self.state.lock();
+            *state = match (*state, self.tri_state) {
+                (CheckState::Unchecked, _) => CheckState::Checked,
+                (CheckState::Checked, true) => CheckState::Indeterminate,
+                (CheckState::Checked, false) => CheckState::Unchecked,
+                (CheckState::Indeterminate, true) => CheckState::Unchecked,
+                (CheckState::Indeterminate, false) => CheckState::Checked,
+            };
+            *state
+        };
+        self.events
+            .push(WidgetEvent::Change(next.as_str().to_string()));
+    }
+}
+
+impl Default for Checkbox {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+impl Widget for Checkbox {
+    fn render(&self, buffer: &mut Buffer, area: Rect, style: &Style, is_focused: bool) {
+        // Apply padding
+        let inner_area = style.shrink(area);
+
+        let mark = match *self.state.lock() {
+            CheckState::Unchecked => "[ ]",
+            CheckState::Checked => "[x]",
+            CheckState::Indeterminate => "[-]",
+        };
+        let mut mark_style = RatatuiStyle::default();
+        if style.disabled {
+            mark_style = mark_style.add_modifier(Modifier::DIM);
+        } else if is_focused {
+            mark_style = mark_style.add_modifier(Modifier::REVERSED);
+        }
+        let line = Line::from(vec![
+            Span::styled(mark, mark_style),
+            Span::raw(" "),
+            Span::raw(self.label.as_str()),
+        ]);
+        Paragraph::new(line).render(inner_area, buffer);
+    }
+
+    fn node_style_hint(&self) -> Option<Style> {
+        Some(
+            Style::new()
+                .no_border()
+                .focusable(true)
+                .disabled(self.disabled),
+        )
+    }
+
+    fn content_size(&self, _area: Rect) -> (u16, u16) {
+        // "[x] " plus the label
+        (self.label.width() as u16 + 4, 1)
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if matches!(key.code, KeyCode::Char(' ') | KeyCode::Enter) {
+            self.toggle();
+            return true;
+        }
+        false
+    }
+
+    fn handle_mouse(&mut self, mouse: MouseEvent, _area: Rect, _style: &Style) -> bool {
+        if mouse.kind == MouseEventKind::Down(MouseButton::Left) {
+            self.toggle();
+            return true;
+        }
+        false
+    }
+
+    fn take_events(&mut self) -> Vec<WidgetEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
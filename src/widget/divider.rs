@@ -80,6 +80,7 @@ impl WidgetType for Divider {
     }
 
     fn create_handle(
+        &self,
         id: String,
         ui_tx: mpsc::Sender<UiMessage>,
         style: crate::style::Style,
@@ -1,4 +1,4 @@
-use crate::event::{UiMessage, WidgetMessage};
+use crate::event::{EventContext, EventStream, EventType, UiMessage, WidgetEvent, WidgetMessage};
 use crate::style::{BorderType, Style};
 use crate::widget::{Widget, WidgetKind, WidgetType};
 use crossterm::event::KeyEvent;
@@ -7,7 +7,8 @@ use ratatui::{
     buffer::Buffer,
     layout::Rect,
     style::{Modifier, Style as RatatuiStyle},
-    widgets::{Block, Borders, Widget as RatatuiWidget},
+    text::Line,
+    widgets::{Block, Widget as RatatuiWidget},
 };
 use ratatui_textarea::TextArea;
 use std::any::Any;
@@ -36,6 +37,30 @@ impl WidgetMessage for InputMessage {
     }
 }
 
+/// Events from an Input widget, see `InputHandle::events`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputEvent {
+    /// The value was edited; carries the new value.
+    Changed(String),
+    /// Enter was pressed; carries the value.
+    Submitted(String),
+    Focused,
+    Blurred,
+}
+
+impl InputEvent {
+    fn from_ctx(ctx: &EventContext) -> Option<Self> {
+        let value = || ctx.value.clone().unwrap_or_default();
+        Some(match ctx.event_type {
+            EventType::Change => InputEvent::Changed(value()),
+            EventType::Submit => InputEvent::Submitted(value()),
+            EventType::Focus => InputEvent::Focused,
+            EventType::Blur => InputEvent::Blurred,
+            _ => return None,
+        })
+    }
+}
+
 /// Handle for controlling an Input widget.
 #[derive(Clone)]
 pub struct InputHandle {
@@ -58,6 +83,21 @@ impl crate::document::WidgetHandle for InputHandle {
 }
 
 impl InputHandle {
+    /// Stream of this widget's value and focus events.
+    pub fn events(&self) -> Result<EventStream<InputEvent>, mpsc::error::TrySendError<UiMessage>> {
+        EventStream::subscribe(
+            &self.id,
+            &self.ui_tx,
+            &[
+                EventType::Change,
+                EventType::Submit,
+                EventType::Focus,
+                EventType::Blur,
+            ],
+            InputEvent::from_ctx,
+        )
+    }
+
     /// Get the current value.
     pub fn get_value(&self) -> String {
         self.textarea.lock().lines().join("\n")
@@ -109,16 +149,16 @@ impl WidgetType for Input {
     }
 
     fn create_handle(
+        &self,
         id: String,
         ui_tx: mpsc::Sender<UiMessage>,
         style: crate::style::Style,
     ) -> Self::Handle {
-        let textarea = Arc::new(Mutex::new(TextArea::default()));
         InputHandle {
             id,
             style,
             ui_tx,
-            textarea: Arc::clone(&textarea),
+            textarea: Arc::clone(&self.textarea),
         }
     }
 }
@@ -128,6 +168,8 @@ pub struct Input {
     textarea: Arc<Mutex<TextArea<'static>>>,
     border_type: Option<BorderType>,
     mask_char: Option<char>,
+    /// Events raised by input, until taken by the render loop.
+    events: Vec<WidgetEvent>,
 }
 
 impl Input {
@@ -143,13 +185,14 @@ impl Input {
             textarea,
             border_type: None,
             mask_char: None,
+            events: Vec::new(),
         }
     }
 
     pub fn with_value<S: Into<String>>(value: S) -> Self {
         let textarea = Arc::new(Mutex::new({
             let mut ta = TextArea::default();
-            ta.insert_str(&value.into());
+            ta.insert_str(value.into());
             ta.set_cursor_line_style(RatatuiStyle::default());
             ta.set_cursor_style(RatatuiStyle::default());
             ta
@@ -159,6 +202,7 @@ impl Input {
             textarea,
             border_type: None,
             mask_char: None,
+            events: Vec::new(),
         }
     }
 
@@ -191,7 +235,7 @@ impl Input {
 
     pub fn set_value<S: Into<String>>(&mut self, value: S) {
         let mut ta = TextArea::default();
-        ta.insert_str(&value.into());
+        ta.insert_str(value.into());
         *self.textarea.lock() = ta;
     }
 }
@@ -209,15 +253,23 @@ impl Widget for Input {
 
         // Show border only if needed
         let block = if self.border_type.is_some() {
-            Block::default()
-                .borders(Borders::ALL)
+            let mut block = Block::default()
+                .borders(style.border_sides.into())
                 .border_type(match self.border_type {
                     Some(BorderType::Plain) => ratatui::widgets::BorderType::Plain,
                     Some(BorderType::Rounded) => ratatui::widgets::BorderType::Rounded,
                     Some(BorderType::Double) => ratatui::widgets::BorderType::Double,
                     Some(BorderType::Thick) => ratatui::widgets::BorderType::Thick,
                     None => ratatui::widgets::BorderType::Plain,
-                })
+                });
+            if let Some(color) = style.border_color_for(is_focused) {
+                block = block.border_style(RatatuiStyle::default().fg(color.into()));
+            }
+            if let Some(title) = &style.title {
+                block =
+                    block.title_top(Line::from(title.as_str()).alignment(style.title_align.into()));
+            }
+            block
         } else {
             Block::default()
         };
@@ -249,10 +301,11 @@ impl Widget for Input {
 
     fn node_style_hint(&self) -> Option<Style> {
         // Return style based on border_type
-        match self.border_type {
-            Some(border_type) => Some(Style::new().border(border_type)),
-            None => Some(Style::new().no_border()),
-        }
+        let style = match self.border_type {
+            Some(border_type) => Style::new().border(border_type),
+            None => Style::new().no_border(),
+        };
+        Some(style.focusable(true))
     }
 
     fn content_size(&self, _area: Rect) -> (u16, u16) {
@@ -264,15 +317,44 @@ impl Widget for Input {
     }
 
     fn handle_key(&mut self, key: KeyEvent) -> bool {
-        // Ignore Enter key in single-line mode
         use crossterm::event::KeyCode;
+        // Enter submits the value in single-line mode
         if key.code == KeyCode::Enter {
+            self.events.push(WidgetEvent::Submit(self.value()));
+            return true;
+        }
+        // Ignore vertical movement, and leave Tab/BackTab to focus navigation
+        if matches!(
+            key.code,
+            KeyCode::Up | KeyCode::Down | KeyCode::Tab | KeyCode::BackTab
+        ) {
             return false;
         }
+        let before = self.value();
         self.textarea.lock().input(key);
+        let after = self.value();
+        if after != before {
+            self.events.push(WidgetEvent::Change(after));
+        }
+        true
+    }
+
+    fn handle_paste(&mut self, text: &str) -> bool {
+        // Single-line: pasted lines are joined with spaces. Terminals may
+        // send CR or CRLF line endings
+        let text = text.replace("\r\n", "\n").replace('\r', "\n");
+        let text = text.lines().collect::<Vec<_>>().join(" ");
+        if !text.is_empty() {
+            self.textarea.lock().insert_str(text);
+            self.events.push(WidgetEvent::Change(self.value()));
+        }
         true
     }
 
+    fn take_events(&mut self) -> Vec<WidgetEvent> {
+        std::mem::take(&mut self.events)
+    }
+
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
@@ -0,0 +1,516 @@
+use crate::event::{EventContext, EventStream, EventType, UiMessage, WidgetEvent, WidgetMessage};
+use crate::style::{BorderType, Style};
+use crate::widget::selection::{Outcome, SelectionMode, SelectionState};
+use crate::widget::{Widget, WidgetKind, WidgetType};
+use crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use parking_lot::Mutex;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style as RatatuiStyle},
+    widgets::{Block, Widget as RatatuiWidget},
+};
+use std::any::Any;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use unicode_width::UnicodeWidthStr;
+
+/// Items, selection and scroll position, shared between a List and its handle.
+#[derive(Debug, Default)]
+struct ListState {
+    selection: SelectionState<String>,
+    /// Width of the widest item, kept until the items change.
+    item_width: Option<usize>,
+}
+
+impl ListState {
+    /// The selected items, in order.
+    fn selected_items(&self) -> Vec<String> {
+        let selection = &self.selection;
+        selection
+            .selected
+            .iter()
+            .filter_map(|&i| selection.items.get(i).cloned())
+            .collect()
+    }
+
+    fn item_width(&mut self) -> usize {
+        *self.item_width.get_or_insert_with(|| {
+            let items = self.selection.items.iter();
+            items.map(|item| item.width()).max().unwrap_or(0)
+        })
+    }
+}
+
+/// Messages for List widget.
+pub enum ListMessage {
+    Append(Vec<String>),
+    Insert(usize, String),
+    Remove(usize),
+    Update(usize, String),
+    SetItems(Vec<String>),
+    /// Move the cursor to an item and select it (or clear the selection).
+    Select(Option<usize>),
+    SetSelected(Vec<usize>),
+    ScrollTo(usize),
+}
+
+impl WidgetMessage for ListMessage {
+    fn apply(self: Box<Self>, widget: &mut dyn Widget) {
+        if let Some(list) = widget.as_any_mut().downcast_mut::<List>() {
+            let mut state = list.state.lock();
+            match *self {
+                ListMessage::Append(items) => {
+                    // Appending only widens the list
+                    if let Some(width) = &mut state.item_width {
+                        *width = items
+                            .iter()
+                            .map(|item| item.width())
+                            .fold(*width, usize::max);
+                    }
+                    state.selection.items.extend(items);
+                }
+                ListMessage::Insert(index, item) => {
+                    state.selection.insert(index, item);
+                    state.item_width = None;
+                }
+                ListMessage::Remove(index) => {
+                    state.selection.remove(index);
+                    state.item_width = None;
+                }
+                ListMessage::Update(index, item) => {
+                    state.selection.update(index, item);
+                    state.item_width = None;
+                }
+                ListMessage::SetItems(items) => {
+                    state.selection.set_items(items);
+                    state.item_width = None;
+                }
+                ListMessage::Select(index) => state.selection.select(index),
+                ListMessage::SetSelected(indices) => state.selection.set_selected(indices),
+                ListMessage::ScrollTo(index) => state.selection.scroll_to(index),
+            }
+        }
+    }
+}
+
+/// Events from a List widget, see `ListHandle::events`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ListEvent {
+    /// The selection changed; carries the selected items.
+    SelectionChanged(Vec<String>),
+    /// Enter was pressed; carries the item under the cursor.
+    Activated(String),
+    Focused,
+    Blurred,
+}
+
+impl ListEvent {
+    fn from_ctx(ctx: &EventContext) -> Option<Self> {
+        let value = || ctx.value.clone().unwrap_or_default();
+        Some(match ctx.event_type {
+            EventType::Change => ListEvent::SelectionChanged(split_selection(&value())),
+            EventType::Submit => ListEvent::Activated(value()),
+            EventType::Focus => ListEvent::Focused,
+            EventType::Blur => ListEvent::Blurred,
+            _ => return None,
+        })
+    }
+}
+
+/// Split a `Change` value back into the selected items.
+fn split_selection(value: &str) -> Vec<String> {
+    if value.is_empty() {
+        return Vec::new();
+    }
+    value.split('\n').map(str::to_string).collect()
+}
+
+/// Handle for controlling a List widget.
+///
+/// Changes are applied by the render loop before the next frame; the getters
+/// read the list's live state.
+#[derive(Clone)]
+pub struct ListHandle {
+    id: String,
+    style: crate::style::Style,
+    ui_tx: mpsc::Sender<UiMessage>,
+    state: Arc<Mutex<ListState>>,
+}
+
+impl crate::document::WidgetHandle for ListHandle {
+    fn id(&self) -> &str {
+        &self.id
+    }
+    fn style(&self) -> &crate::style::Style {
+        &self.style
+    }
+    fn ui_tx(&self) -> &mpsc::Sender<UiMessage> {
+        &self.ui_tx
+    }
+}
+
+impl ListHandle {
+    fn send(&self, message: ListMessage) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.ui_tx.try_send(UiMessage::WidgetMessage {
+            id: self.id.clone(),
+            message: Box::new(message),
+        })?;
+        Ok(())
+    }
+
+    /// Number of items.
+    pub fn len(&self) -> usize {
+        self.state.lock().selection.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.state.lock().selection.items.is_empty()
+    }
+
+    /// Get an item.
+    pub fn get(&self, index: usize) -> Option<String> {
+        self.state.lock().selection.items.get(index).cloned()
+    }
+
+    /// Get all items.
+    pub fn items(&self) -> Vec<String> {
+        self.state.lock().selection.items.clone()
+    }
+
+    /// Indices of the selected items, in order.
+    pub fn selected(&self) -> Vec<usize> {
+        self.state
+            .lock()
+            .selection
+            .selected
+            .iter()
+            .copied()
+            .collect()
+    }
+
+    /// The selected items, in order.
+    pub fn selected_items(&self) -> Vec<String> {
+        self.state.lock().selected_items()
+    }
+
+    /// Index of the item under the cursor.
+    pub fn cursor(&self) -> Option<usize> {
+        self.state.lock().selection.cursor
+    }
+
+    /// Add an item at the end.
+    pub fn append(
+        &self,
+        item: impl Into<String>,
+    ) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.send(ListMessage::Append(vec![item.into()]))
+    }
+
+    /// Add items at the end.
+    pub fn extend<I, S>(&self, items: I) -> Result<(), mpsc::error::TrySendError<UiMessage>>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.send(ListMessage::Append(
+            items.into_iter().map(Into::into).collect(),
+        ))
+    }
+
+    /// Insert an item before `index`; selection and cursor stay on their items.
+    pub fn insert(
+        &self,
+        index: usize,
+        item: impl Into<String>,
+    ) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.send(ListMessage::Insert(index, item.into()))
+    }
+
+    /// Remove an item; selection and cursor stay on their items.
+    pub fn remove(&self, index: usize) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.send(ListMessage::Remove(index))
+    }
+
+    /// Replace an item's text.
+    pub fn update(
+        &self,
+        index: usize,
+        item: impl Into<String>,
+    ) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.send(ListMessage::Update(index, item.into()))
+    }
+
+    /// Replace all items. Selected indices past the new end are dropped.
+    pub fn set_items<I, S>(&self, items: I) -> Result<(), mpsc::error::TrySendError<UiMessage>>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.send(ListMessage::SetItems(
+            items.into_iter().map(Into::into).collect(),
+        ))
+    }
+
+    /// Remove all items.
+    pub fn clear(&self) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.send(ListMessage::SetItems(Vec::new()))
+    }
+
+    /// Select one item and move the cursor to it, or clear the selection.
+    /// Doesn't fire `Change`.
+    pub fn select(&self, index: Option<usize>) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.send(ListMessage::Select(index))
+    }
+
+    /// Replace the selection (multiple selection). Doesn't fire `Change`.
+    pub fn set_selected(
+        &self,
+        indices: impl IntoIterator<Item = usize>,
+    ) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.send(ListMessage::SetSelected(indices.into_iter().collect()))
+    }
+
+    /// Scroll so that `index` is the first visible item.
+    pub fn scroll_to(&self, index: usize) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.send(ListMessage::ScrollTo(index))
+    }
+
+    /// Stream of this list's selection, activation and focus events.
+    pub fn events(&self) -> Result<EventStream<ListEvent>, mpsc::error::TrySendError<UiMessage>> {
+        EventStream::subscribe(
+            &self.id,
+            &self.ui_tx,
+            &[
+                EventType::Change,
+                EventType::Submit,
+                EventType::Focus,
+                EventType::Blur,
+            ],
+            ListEvent::from_ctx,
+        )
+    }
+}
+
+impl WidgetType for List {
+    type Handle = ListHandle;
+
+    fn kind() -> WidgetKind {
+        WidgetKind::List
+    }
+
+    fn create_handle(
+        &self,
+        id: String,
+        ui_tx: mpsc::Sender<UiMessage>,
+        style: crate::style::Style,
+    ) -> Self::Handle {
+        ListHandle {
+            id,
+            style,
+            ui_tx,
+            state: Arc::clone(&self.state),
+        }
+    }
+}
+
+/// A scrollable list of selectable items.
+///
+/// Only the visible rows are drawn, so lists of many thousands of items stay
+/// cheap. Keys: Up/Down, PageUp/PageDown, Home/End, Enter (`Submit` with the
+/// item under the cursor), and typing jumps to the next item starting with
+/// the typed text. `Change` events carry the selected items, one per line.
+pub struct List {
+    state: Arc<Mutex<ListState>>,
+    border_type: Option<BorderType>,
+    /// Events raised by input, until taken by the render loop.
+    events: Vec<WidgetEvent>,
+}
+
+impl List {
+    pub fn new<I, S>(items: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        List {
+            state: Arc::new(Mutex::new(ListState {
+                selection: SelectionState::new(items.into_iter().map(Into::into).collect()),
+                item_width: None,
+            })),
+            border_type: None,
+            events: Vec::new(),
+        }
+    }
+
+    /// Set the selection mode.
+    pub fn selection_mode(self, mode: SelectionMode) -> Self {
+        self.state.lock().selection.mode = mode;
+        self
+    }
+
+    /// Create a list with border.
+    pub fn bordered(mut self, border_type: BorderType) -> Self {
+        self.border_type = Some(border_type);
+        self
+    }
+
+    /// Start with an item selected and under the cursor.
+    pub fn selected(self, index: usize) -> Self {
+        {
+            let state = &mut self.state.lock().selection;
+            if index < state.items.len() {
+                state.selected = BTreeSet::from([index]);
+                state.cursor = Some(index);
+            }
+        }
+        self
+    }
+
+    pub fn get_selected(&self) -> Vec<usize> {
+        self.state
+            .lock()
+            .selection
+            .selected
+            .iter()
+            .copied()
+            .collect()
+    }
+
+    fn block(&self, style: &Style, is_focused: bool) -> Block<'static> {
+        let Some(border_type) = self.border_type else {
+            return Block::default();
+        };
+        let mut block = Block::default()
+            .borders(style.border_sides.into())
+            .border_type(match border_type {
+                BorderType::Plain => ratatui::widgets::BorderType::Plain,
+                BorderType::Rounded => ratatui::widgets::BorderType::Rounded,
+                BorderType::Double => ratatui::widgets::BorderType::Double,
+                BorderType::Thick => ratatui::widgets::BorderType::Thick,
+            });
+        if let Some(color) = style.border_color_for(is_focused) {
+            block = block.border_style(RatatuiStyle::default().fg(color.into()));
+        }
+        block
+    }
+
+    /// Queue the events for what a key or click did.
+    fn dispatch(&mut self, outcome: Outcome) -> bool {
+        match outcome {
+            Outcome::Ignored => return false,
+            Outcome::Handled => {}
+            Outcome::Changed => {
+                let value = self.state.lock().selected_items().join("\n");
+                self.events.push(WidgetEvent::Change(value));
+            }
+            Outcome::Activated(index) => {
+                let item = self.state.lock().selection.items[index].clone();
+                self.events.push(WidgetEvent::Submit(item));
+            }
+        }
+        true
+    }
+}
+
+impl Default for List {
+    fn default() -> Self {
+        Self::new(Vec::<String>::new())
+    }
+}
+
+impl Widget for List {
+    fn render(&self, buffer: &mut Buffer, area: Rect, style: &Style, is_focused: bool) {
+        // Apply padding
+        let inner_area = style.shrink(area);
+        let block = self.block(style, is_focused);
+        let rows_area = block.inner(inner_area);
+        block.render(inner_area, buffer);
+
+        let state = &mut self.state.lock().selection;
+        // Only the visible rows are drawn
+        let visible = state.layout(rows_area.height as usize);
+        for (row, index) in visible.enumerate() {
+            let y = rows_area.y + row as u16;
+            let selected = state.selected.contains(&index);
+            let text = match state.mode {
+                SelectionMode::Single => state.items[index].clone(),
+                SelectionMode::Multiple => {
+                    let mark = if selected { "[x] " } else { "[ ] " };
+                    format!("{}{}", mark, state.items[index])
+                }
+            };
+            let mut row_style = RatatuiStyle::default();
+            if style.disabled {
+                row_style = row_style.add_modifier(Modifier::DIM);
+            } else if is_focused && state.cursor == Some(index) {
+                row_style = row_style.add_modifier(Modifier::REVERSED);
+            } else if selected {
+                row_style = row_style.add_modifier(Modifier::BOLD);
+            }
+            let row_area = Rect::new(rows_area.x, y, rows_area.width, 1);
+            buffer.set_style(row_area, row_style);
+            buffer.set_stringn(rows_area.x, y, text, rows_area.width as usize, row_style);
+        }
+    }
+
+    fn node_style_hint(&self) -> Option<Style> {
+        let style = match self.border_type {
+            Some(border_type) => Style::new().border(border_type),
+            None => Style::new().no_border(),
+        };
+        Some(style.focusable(true))
+    }
+
+    fn content_size(&self, _area: Rect) -> (u16, u16) {
+        let mut state = self.state.lock();
+        let mark = match state.selection.mode {
+            SelectionMode::Single => 0,
+            SelectionMode::Multiple => 4,
+        };
+        let width = state.item_width() + mark;
+        let border = if self.border_type.is_some() { 2 } else { 0 };
+        (
+            (width + border).min(u16::MAX as usize) as u16,
+            (state.selection.items.len() + border).min(u16::MAX as usize) as u16,
+        )
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let outcome = self.state.lock().selection.handle_key(key, String::as_str);
+        self.dispatch(outcome)
+    }
+
+    fn handle_mouse(&mut self, mouse: MouseEvent, area: Rect, style: &Style) -> bool {
+        let rows_area = self.block(style, false).inner(style.shrink(area));
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let Some(row) = mouse.row.checked_sub(rows_area.y) else {
+                    return false;
+                };
+                if row >= rows_area.height {
+                    return false;
+                }
+                let outcome = {
+                    let state = &mut self.state.lock().selection;
+                    let index = state.offset + row as usize;
+                    state.click(index)
+                };
+                self.dispatch(outcome)
+            }
+            // The wheel scrolls the view without moving the cursor
+            MouseEventKind::ScrollUp => self.state.lock().selection.wheel(false),
+            MouseEventKind::ScrollDown => self.state.lock().selection.wheel(true),
+            _ => false,
+        }
+    }
+
+    fn take_events(&mut self) -> Vec<WidgetEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
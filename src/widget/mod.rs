@@ -1,17 +1,58 @@
 use crate::style::Style;
-use crossterm::event::KeyEvent;
+use crossterm::event::{KeyEvent, MouseEvent};
 use ratatui::{buffer::Buffer, layout::Rect};
 use tokio::sync::mpsc;
 
-use crate::event::UiMessage;
+use crate::event::{UiMessage, WidgetEvent};
 
 /// Widget type identifier.
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum WidgetKind {
     Input,
     Textarea,
     Text,
     Divider,
+    Button,
+    Checkbox,
+    RadioGroup,
+    Switch,
+    List,
+    Table,
+}
+
+impl WidgetKind {
+    /// Lowercase name used in stylesheet selectors.
+    pub fn name(self) -> &'static str {
+        match self {
+            WidgetKind::Input => "input",
+            WidgetKind::Textarea => "textarea",
+            WidgetKind::Text => "text",
+            WidgetKind::Divider => "divider",
+            WidgetKind::Button => "button",
+            WidgetKind::Checkbox => "checkbox",
+            WidgetKind::RadioGroup => "radio-group",
+            WidgetKind::Switch => "switch",
+            WidgetKind::List => "list",
+            WidgetKind::Table => "table",
+        }
+    }
+
+    /// Parse a lowercase kind name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "input" => WidgetKind::Input,
+            "textarea" => WidgetKind::Textarea,
+            "text" => WidgetKind::Text,
+            "divider" => WidgetKind::Divider,
+            "button" => WidgetKind::Button,
+            "checkbox" => WidgetKind::Checkbox,
+            "radio-group" => WidgetKind::RadioGroup,
+            "switch" => WidgetKind::Switch,
+            "list" => WidgetKind::List,
+            "table" => WidgetKind::Table,
+            _ => return None,
+        })
+    }
 }
 
 /// Trait to associate a Widget with its Handle type.
@@ -20,7 +61,10 @@ pub trait WidgetType {
 
     fn kind() -> WidgetKind;
 
+    /// Create the handle; it shares the widget's state (e.g. its value), so
+    /// getters on the handle see changes made in the render loop.
     fn create_handle(
+        &self,
         id: String,
         ui_tx: mpsc::Sender<UiMessage>,
         style: crate::style::Style,
@@ -30,6 +74,9 @@ pub trait WidgetType {
 /// A renderable widget that can be displayed in a terminal area.
 pub trait Widget: Send + Sync {
     /// Render the widget within the given buffer and area.
+    ///
+    /// `style` has theme colors resolved against the active theme, so its
+    /// colors convert to ratatui colors with `into()`.
     fn render(&self, buffer: &mut Buffer, area: Rect, style: &Style, is_focused: bool);
 
     /// Get the default style hint for the node that contains this widget.
@@ -62,16 +109,51 @@ pub trait Widget: Send + Sync {
         false
     }
 
+    /// Handle a mouse press or wheel event over the widget, or the release of
+    /// a left press that started on it.
+    /// `area` and `style` are the same as in `render`, and the event's
+    /// column/row are in the same coordinates as `area`.
+    /// Returns true if the event was handled, false otherwise.
+    fn handle_mouse(&mut self, _mouse: MouseEvent, _area: Rect, _style: &Style) -> bool {
+        false
+    }
+
+    /// Insert pasted text when the widget has focus.
+    /// Returns true if the paste was handled, false otherwise.
+    fn handle_paste(&mut self, _text: &str) -> bool {
+        false
+    }
+
+    /// Take the events raised since the last call, e.g. `WidgetEvent::Change`.
+    /// Called by the render loop after input has been handled.
+    fn take_events(&mut self) -> Vec<WidgetEvent> {
+        Vec::new()
+    }
+
     /// Get mutable reference as Any for downcasting.
     fn as_any_mut(&mut self) -> &mut dyn std::any::Any;
 }
 
+pub mod button;
+pub mod checkbox;
 pub mod divider;
 pub mod input;
+pub mod list;
+pub mod radio;
+pub mod selection;
+pub mod switch;
+pub mod table;
 pub mod text;
 pub mod textarea;
 
+pub use button::{Button, ButtonEvent, ButtonHandle};
+pub use checkbox::{CheckState, Checkbox, CheckboxEvent, CheckboxHandle};
 pub use divider::{Direction, Divider, DividerHandle};
-pub use input::{Input, InputHandle};
+pub use input::{Input, InputEvent, InputHandle};
+pub use list::{List, ListEvent, ListHandle};
+pub use radio::{RadioGroup, RadioGroupEvent, RadioGroupHandle};
+pub use selection::SelectionMode;
+pub use switch::{Switch, SwitchEvent, SwitchHandle};
+pub use table::{Column, ColumnWidth, SortOrder, Table, TableEvent, TableHandle};
 pub use text::{Text, TextHandle};
-pub use textarea::{Textarea, TextareaHandle};
+pub use textarea::{Textarea, TextareaEvent, TextareaHandle};
@@ -0,0 +1,386 @@
+use crate::event::{EventContext, EventStream, EventType, UiMessage, WidgetEvent, WidgetMessage};
+use crate::style::Style;
+use crate::widget::{Widget, WidgetKind, WidgetType};
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use parking_lot::Mutex;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style as RatatuiStyle},
+    text::{Line, Span},
+    widgets::{Paragraph, Widget as RatatuiWidget},
+};
+use std::any::Any;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use unicode_width::UnicodeWidthStr;
+
+/// Gap between options in a horizontal group.
+const OPTION_GAP: u16 = 2;
+
+/// Messages for RadioGroup widget.
+pub enum RadioGroupMessage {
+    Select(Option<usize>),
+    SetOptions(Vec<String>),
+}
+
+impl WidgetMessage for RadioGroupMessage {
+    fn apply(self: Box<Self>, widget: &mut dyn Widget) {
+        if let Some(group) = widget.as_any_mut().downcast_mut::<RadioGroup>() {
+            match *self {
+                RadioGroupMessage::Select(index) => group.select(index),
+                RadioGroupMessage::SetOptions(options) => group.set_options(options),
+            }
+        }
+    }
+}
+
+/// Events from a RadioGroup widget, see `RadioGroupHandle::events`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RadioGroupEvent {
+    /// The selected option's label.
+    Changed(String),
+    Focused,
+    Blurred,
+}
+
+impl RadioGroupEvent {
+    fn from_ctx(ctx: &EventContext) -> Option<Self> {
+        Some(match ctx.event_type {
+            EventType::Change => RadioGroupEvent::Changed(ctx.value.clone()?),
+            EventType::Focus => RadioGroupEvent::Focused,
+            EventType::Blur => RadioGroupEvent::Blurred,
+            _ => return None,
+        })
+    }
+}
+
+/// Handle for controlling a RadioGroup widget.
+#[derive(Clone)]
+pub struct RadioGroupHandle {
+    id: String,
+    style: crate::style::Style,
+    ui_tx: mpsc::Sender<UiMessage>,
+    options: Arc<Mutex<Vec<String>>>,
+    selected: Arc<Mutex<Option<usize>>>,
+}
+
+impl crate::document::WidgetHandle for RadioGroupHandle {
+    fn id(&self) -> &str {
+        &self.id
+    }
+    fn style(&self) -> &crate::style::Style {
+        &self.style
+    }
+    fn ui_tx(&self) -> &mpsc::Sender<UiMessage> {
+        &self.ui_tx
+    }
+}
+
+impl RadioGroupHandle {
+    /// Index of the selected option.
+    pub fn selected(&self) -> Option<usize> {
+        *self.selected.lock()
+    }
+
+    /// Label of the selected option.
+    pub fn selected_option(&self) -> Option<String> {
+        let index = (*self.selected.lock())?;
+        self.options.lock().get(index).cloned()
+    }
+
+    /// Get the options.
+    pub fn options(&self) -> Vec<String> {
+        self.options.lock().clone()
+    }
+
+    /// Select an option by index, or clear the selection. Doesn't fire `Change`.
+    pub fn select(&self, index: Option<usize>) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.ui_tx.try_send(UiMessage::WidgetMessage {
+            id: self.id.clone(),
+            message: Box::new(RadioGroupMessage::Select(index)),
+        })?;
+        Ok(())
+    }
+
+    /// Replace the options. The selection is cleared if it no longer exists.
+    pub fn set_options<I, S>(&self, options: I) -> Result<(), mpsc::error::TrySendError<UiMessage>>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.ui_tx.try_send(UiMessage::WidgetMessage {
+            id: self.id.clone(),
+            message: Box::new(RadioGroupMessage::SetOptions(
+                options.into_iter().map(Into::into).collect(),
+            )),
+        })?;
+        Ok(())
+    }
+
+    /// Stream of this group's change and focus events.
+    pub fn events(
+        &self,
+    ) -> Result<EventStream<RadioGroupEvent>, mpsc::error::TrySendError<UiMessage>> {
+        EventStream::subscribe(
+            &self.id,
+            &self.ui_tx,
+            &[EventType::Change, EventType::Focus, EventType::Blur],
+            RadioGroupEvent::from_ctx,
+        )
+    }
+}
+
+impl WidgetType for RadioGroup {
+    type Handle = RadioGroupHandle;
+
+    fn kind() -> WidgetKind {
+        WidgetKind::RadioGroup
+    }
+
+    fn create_handle(
+        &self,
+        id: String,
+        ui_tx: mpsc::Sender<UiMessage>,
+        style: crate::style::Style,
+    ) -> Self::Handle {
+        RadioGroupHandle {
+            id,
+            style,
+            ui_tx,
+            options: Arc::clone(&self.options),
+            selected: Arc::clone(&self.selected),
+        }
+    }
+}
+
+/// A group of mutually exclusive options, one per line (or in a row with
+/// `horizontal(true)`).
+///
+/// The arrow keys move the selection, wrapping around; Home and End jump to
+/// the first and last option. `Change` events carry the selected option's label.
+pub struct RadioGroup {
+    options: Arc<Mutex<Vec<String>>>,
+    selected: Arc<Mutex<Option<usize>>>,
+    horizontal: bool,
+    disabled: bool,
+    /// Events raised by input, until taken by the render loop.
+    events: Vec<WidgetEvent>,
+}
+
+impl RadioGroup {
+    pub fn new<I, S>(options: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        RadioGroup {
+            options: Arc::new(Mutex::new(options.into_iter().map(Into::into).collect())),
+            selected: Arc::new(Mutex::new(None)),
+            horizontal: false,
+            disabled: false,
+            events: Vec::new(),
+        }
+    }
+
+    /// Start with an option selected.
+    pub fn selected(self, index: usize) -> Self {
+        *self.selected.lock() = Some(index).filter(|&i| i < self.options.lock().len());
+        self
+    }
+
+    /// Lay the options out in a row instead of a column.
+    pub fn horizontal(mut self, horizontal: bool) -> Self {
+        self.horizontal = horizontal;
+        self
+    }
+
+    /// Start disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn get_selected(&self) -> Option<usize> {
+        *self.selected.lock()
+    }
+
+    /// Select an option by index (ignored if out of range), or clear the selection.
+    pub fn select(&mut self, index: Option<usize>) {
+        if index.is_none_or(|i| i < self.options.lock().len()) {
+            *self.selected.lock() = index;
+        }
+    }
+
+    pub fn set_options(&mut self, options: Vec<String>) {
+        let mut selected = self.selected.lock();
+        if selected.is_some_and(|i| i >= options.len()) {
+            *selected = None;
+        }
+        *self.options.lock() = options;
+    }
+
+    /// Select from user input, firing `Change` if the selection moved.
+    fn choose(&mut self, index: usize) {
+        let Some(label) = self.options.lock().get(index).cloned() else {
+            return;
+        };
+        let previous = self.selected.lock().replace(index);
+        if previous != Some(index) {
+            self.events.push(WidgetEvent::Change(label));
+        }
+    }
+
+    /// Move the selection by `step`, wrapping around.
+    fn step(&mut self, step: isize) {
+        let len = self.options.lock().len() as isize;
+        if len == 0 {
+            return;
+        }
+        let index = match *self.selected.lock() {
+            Some(i) => (i as isize + step).rem_euclid(len),
+            // Nothing selected yet: start from the end the key points away from
+            None if step > 0 => 0,
+            None => len - 1,
+        };
+        self.choose(index as usize);
+    }
+
+    /// Option under a point inside the content area.
+    fn option_at(&self, col: u16, row: u16) -> Option<usize> {
+        let options = self.options.lock();
+        if !self.horizontal {
+            return ((row as usize) < options.len()).then_some(row as usize);
+        }
+        if row != 0 {
+            return None;
+        }
+        let mut x = 0;
+        for (i, option) in options.iter().enumerate() {
+            let width = option_width(option);
+            if col < x + width {
+                return Some(i);
+            }
+            x += width + OPTION_GAP;
+        }
+        None
+    }
+}
+
+/// Width of one option: "(•) " plus the label.
+fn option_width(option: &str) -> u16 {
+    option.width() as u16 + 4
+}
+
+impl Widget for RadioGroup {
+    fn render(&self, buffer: &mut Buffer, area: Rect, style: &Style, is_focused: bool) {
+        // Apply padding
+        let inner_area = style.shrink(area);
+
+        let options = self.options.lock();
+        let selected = *self.selected.lock();
+        let mut mark_style = RatatuiStyle::default();
+        if style.disabled {
+            mark_style = mark_style.add_modifier(Modifier::DIM);
+        }
+        let spans = |i: usize, option: &str| {
+            let is_selected = selected == Some(i);
+            let mark = if is_selected { "(•)" } else { "( )" };
+            // Highlight the selection, or the first option if none is selected
+            let current = is_selected || (selected.is_none() && i == 0);
+            let style = if is_focused && current && !style.disabled {
+                mark_style.add_modifier(Modifier::REVERSED)
+            } else {
+                mark_style
+            };
+            vec![
+                Span::styled(mark, style),
+                Span::raw(" "),
+                Span::raw(option.to_string()),
+            ]
+        };
+
+        let lines: Vec<Line> = if self.horizontal {
+            let gap = " ".repeat(OPTION_GAP as usize);
+            let mut row = Vec::new();
+            for (i, option) in options.iter().enumerate() {
+                if i > 0 {
+                    row.push(Span::raw(gap.clone()));
+                }
+                row.extend(spans(i, option));
+            }
+            vec![Line::from(row)]
+        } else {
+            options
+                .iter()
+                .enumerate()
+                .map(|(i, option)| Line::from(spans(i, option)))
+                .collect()
+        };
+        Paragraph::new(lines).render(inner_area, buffer);
+    }
+
+    fn node_style_hint(&self) -> Option<Style> {
+        Some(
+            Style::new()
+                .no_border()
+                .focusable(true)
+                .disabled(self.disabled),
+        )
+    }
+
+    fn content_size(&self, _area: Rect) -> (u16, u16) {
+        let options = self.options.lock();
+        let widths = options.iter().map(|option| option_width(option));
+        if self.horizontal {
+            let gaps = options.len().saturating_sub(1) as u16 * OPTION_GAP;
+            (widths.sum::<u16>() + gaps, 1)
+        } else {
+            (widths.max().unwrap_or(0), options.len() as u16)
+        }
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Up | KeyCode::Left => self.step(-1),
+            KeyCode::Down | KeyCode::Right => self.step(1),
+            KeyCode::Home => self.choose(0),
+            KeyCode::End => {
+                let len = self.options.lock().len();
+                self.choose(len.saturating_sub(1));
+            }
+            // Space selects the highlighted option when nothing is selected
+            KeyCode::Char(' ') if self.get_selected().is_none() => self.choose(0),
+            _ => return false,
+        }
+        true
+    }
+
+    fn handle_mouse(&mut self, mouse: MouseEvent, area: Rect, style: &Style) -> bool {
+        if mouse.kind != MouseEventKind::Down(MouseButton::Left) {
+            return false;
+        }
+        let inner_area = style.shrink(area);
+        let (Some(col), Some(row)) = (
+            mouse.column.checked_sub(inner_area.x),
+            mouse.row.checked_sub(inner_area.y),
+        ) else {
+            return false;
+        };
+        match self.option_at(col, row) {
+            Some(index) => {
+                self.choose(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn take_events(&mut self) -> Vec<WidgetEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -0,0 +1,404 @@
+//! Cursor, selection, scrolling and type-to-search shared by `List` and `Table`.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::BTreeSet;
+use std::ops::Range;
+use std::time::{Duration, Instant};
+
+/// Typed characters within this interval extend the search prefix.
+const SEARCH_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// Rows moved per mouse wheel step.
+const WHEEL_ROWS: usize = 3;
+
+/// How many items a List or Table lets the user select.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelectionMode {
+    /// The selection follows the cursor.
+    #[default]
+    Single,
+    /// Space and clicks toggle items, Shift+arrows select the items moved
+    /// onto; Ctrl+A selects all.
+    Multiple,
+}
+
+/// What a key or click did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Outcome {
+    /// The input wasn't used.
+    Ignored,
+    /// Used, without changing the selection.
+    Handled,
+    /// The selection changed.
+    Changed,
+    /// Enter was pressed on this item.
+    Activated(usize),
+}
+
+/// Items with a cursor, a selection and a scroll position.
+///
+/// Indices in `cursor` and `selected` follow their items through inserts,
+/// removals and reordering.
+#[derive(Debug, Default)]
+pub(crate) struct SelectionState<T> {
+    pub items: Vec<T>,
+    pub mode: SelectionMode,
+    /// The item keyboard navigation starts from.
+    pub cursor: Option<usize>,
+    pub selected: BTreeSet<usize>,
+    /// First visible item.
+    pub offset: usize,
+    /// Rows shown by the last render, used for paging.
+    pub viewport: usize,
+    /// Type-to-search prefix and when it was last extended.
+    search: String,
+    last_search: Option<Instant>,
+}
+
+impl<T> SelectionState<T> {
+    pub fn new(items: Vec<T>) -> Self {
+        SelectionState {
+            items,
+            mode: SelectionMode::Single,
+            cursor: None,
+            selected: BTreeSet::new(),
+            offset: 0,
+            viewport: 0,
+            search: String::new(),
+            last_search: None,
+        }
+    }
+
+    pub fn insert(&mut self, index: usize, item: T) {
+        let index = index.min(self.items.len());
+        self.items.insert(index, item);
+        let shift = |i: usize| if i >= index { i + 1 } else { i };
+        self.selected = self.selected.iter().map(|&i| shift(i)).collect();
+        self.cursor = self.cursor.map(shift);
+    }
+
+    pub fn remove(&mut self, index: usize) {
+        if index >= self.items.len() {
+            return;
+        }
+        self.items.remove(index);
+        self.selected = self
+            .selected
+            .iter()
+            .filter(|&&i| i != index)
+            .map(|&i| if i > index { i - 1 } else { i })
+            .collect();
+        self.cursor = match self.cursor {
+            Some(i) if i > index => Some(i - 1),
+            // Keep the cursor in place, or on the new last item
+            Some(i) => Some(i)
+                .filter(|&i| i < self.items.len())
+                .or(self.items.len().checked_sub(1)),
+            None => None,
+        };
+    }
+
+    pub fn update(&mut self, index: usize, item: T) {
+        if let Some(slot) = self.items.get_mut(index) {
+            *slot = item;
+        }
+    }
+
+    pub fn set_items(&mut self, items: Vec<T>) {
+        self.items = items;
+        let len = self.items.len();
+        self.selected.retain(|&i| i < len);
+        self.cursor = self.cursor.filter(|&i| i < len);
+    }
+
+    /// Reorder the items so that item `permutation[i]` ends up at `i`.
+    pub fn reorder(&mut self, permutation: &[usize]) {
+        let mut new_index = vec![0; permutation.len()];
+        for (new, &old) in permutation.iter().enumerate() {
+            new_index[old] = new;
+        }
+        let mut items: Vec<Option<T>> = std::mem::take(&mut self.items)
+            .into_iter()
+            .map(Some)
+            .collect();
+        self.items = permutation
+            .iter()
+            .filter_map(|&old| items[old].take())
+            .collect();
+        self.selected = self.selected.iter().map(|&i| new_index[i]).collect();
+        self.cursor = self.cursor.map(|i| new_index[i]);
+    }
+
+    /// Move the cursor to an item and select only it, or clear the selection.
+    pub fn select(&mut self, index: Option<usize>) {
+        let index = index.filter(|&i| i < self.items.len());
+        self.selected = index.into_iter().collect();
+        if index.is_some() {
+            self.cursor = index;
+            self.ensure_visible();
+        }
+    }
+
+    pub fn set_selected(&mut self, indices: impl IntoIterator<Item = usize>) {
+        let len = self.items.len();
+        self.selected = indices.into_iter().filter(|&i| i < len).collect();
+    }
+
+    pub fn scroll_to(&mut self, index: usize) {
+        self.offset = index.min(self.items.len().saturating_sub(1));
+    }
+
+    /// Scroll so that the cursor is visible.
+    pub fn ensure_visible(&mut self) {
+        let Some(cursor) = self.cursor else {
+            return;
+        };
+        let viewport = self.viewport.max(1);
+        if cursor < self.offset {
+            self.offset = cursor;
+        } else if cursor >= self.offset + viewport {
+            self.offset = cursor + 1 - viewport;
+        }
+    }
+
+    /// Record the rows available for drawing and return the visible items.
+    pub fn layout(&mut self, viewport: usize) -> Range<usize> {
+        self.viewport = viewport;
+        let max_offset = self.items.len().saturating_sub(viewport);
+        self.offset = self.offset.min(max_offset);
+        self.offset..(self.offset + viewport).min(self.items.len())
+    }
+
+    /// Move the cursor; in single mode the selection follows it.
+    /// With `extend` (multiple mode), the item moved onto is selected.
+    fn move_to(&mut self, index: usize, extend: bool) -> Outcome {
+        let Some(last) = self.items.len().checked_sub(1) else {
+            return Outcome::Handled;
+        };
+        let index = index.min(last);
+        self.cursor = Some(index);
+        self.ensure_visible();
+        let changed = match self.mode {
+            SelectionMode::Single => {
+                let changed = self.selected.len() != 1 || !self.selected.contains(&index);
+                self.selected = BTreeSet::from([index]);
+                changed
+            }
+            SelectionMode::Multiple if extend => self.selected.insert(index),
+            SelectionMode::Multiple => false,
+        };
+        if changed {
+            Outcome::Changed
+        } else {
+            Outcome::Handled
+        }
+    }
+
+    /// Toggle an item's selection (multiple mode).
+    fn toggle(&mut self, index: usize) -> Outcome {
+        if index >= self.items.len() {
+            return Outcome::Handled;
+        }
+        if !self.selected.remove(&index) {
+            self.selected.insert(index);
+        }
+        Outcome::Changed
+    }
+
+    /// A click on an item: select it, or toggle it in multiple mode.
+    pub fn click(&mut self, index: usize) -> Outcome {
+        if index >= self.items.len() {
+            return Outcome::Ignored;
+        }
+        match self.mode {
+            SelectionMode::Single => self.move_to(index, false),
+            SelectionMode::Multiple => {
+                self.cursor = Some(index);
+                self.toggle(index)
+            }
+        }
+    }
+
+    /// Scroll by wheel steps without moving the cursor. Returns false at
+    /// either end, so the wheel can go to an enclosing ScrollView.
+    pub fn wheel(&mut self, down: bool) -> bool {
+        let offset = self.offset;
+        self.offset = if down {
+            let max_offset = self.items.len().saturating_sub(self.viewport);
+            (offset + WHEEL_ROWS).min(max_offset).max(offset)
+        } else {
+            offset.saturating_sub(WHEEL_ROWS)
+        };
+        self.offset != offset
+    }
+
+    /// Navigation, selection and type-to-search keys. `label` is the text
+    /// typed characters are matched against.
+    pub fn handle_key(&mut self, key: KeyEvent, label: impl Fn(&T) -> &str) -> Outcome {
+        let (cursor, len, page) = (self.cursor, self.items.len(), self.viewport.max(1));
+        let multiple = self.mode == SelectionMode::Multiple;
+        let extend = multiple && key.modifiers.contains(KeyModifiers::SHIFT);
+        match key.code {
+            KeyCode::Up => self.move_to(cursor.map_or(0, |i| i.saturating_sub(1)), extend),
+            KeyCode::Down => self.move_to(cursor.map_or(0, |i| i + 1), extend),
+            KeyCode::PageUp => self.move_to(cursor.unwrap_or(0).saturating_sub(page), extend),
+            KeyCode::PageDown => self.move_to(cursor.map_or(0, |i| i + page), extend),
+            KeyCode::Home => self.move_to(0, extend),
+            KeyCode::End => self.move_to(len.saturating_sub(1), extend),
+            KeyCode::Enter => cursor
+                .filter(|&i| i < len)
+                .map_or(Outcome::Ignored, Outcome::Activated),
+            KeyCode::Char('a') if key.modifiers.contains(KeyModifiers::CONTROL) && multiple => {
+                self.selected = (0..len).collect();
+                Outcome::Changed
+            }
+            KeyCode::Char(' ') if multiple && !self.is_searching() => match cursor {
+                Some(cursor) => self.toggle(cursor),
+                None => Outcome::Handled,
+            },
+            KeyCode::Char(c)
+                if !key
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                self.search(c, label)
+            }
+            _ => Outcome::Ignored,
+        }
+    }
+
+    /// Extend the type-to-search prefix and jump to the next match.
+    fn search(&mut self, c: char, label: impl Fn(&T) -> &str) -> Outcome {
+        let now = Instant::now();
+        if self
+            .last_search
+            .is_none_or(|last| now.duration_since(last) > SEARCH_TIMEOUT)
+        {
+            self.search.clear();
+        }
+        self.last_search = Some(now);
+        self.search.extend(c.to_lowercase());
+
+        let len = self.items.len();
+        let cursor = self.cursor.unwrap_or(0);
+        // A new search starts after the cursor, a longer prefix may stay on it
+        let start = if self.search.chars().count() == 1 {
+            cursor + 1
+        } else {
+            cursor
+        };
+        let found = (0..len).map(|i| (start + i) % len).find(|&i| {
+            label(&self.items[i])
+                .to_lowercase()
+                .starts_with(self.search.as_str())
+        });
+        match found {
+            Some(index) => self.move_to(index, false),
+            None => Outcome::Handled,
+        }
+    }
+
+    fn is_searching(&self) -> bool {
+        !self.search.is_empty()
+            && self
+                .last_search
+                .is_some_and(|last| last.elapsed() <= SEARCH_TIMEOUT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(items: &[&'static str]) -> SelectionState<&'static str> {
+        SelectionState::new(items.to_vec())
+    }
+
+    fn press(state: &mut SelectionState<&str>, code: KeyCode, modifiers: KeyModifiers) -> Outcome {
+        state.handle_key(KeyEvent::new(code, modifiers), |item| item)
+    }
+
+    #[test]
+    fn inserts_and_removals_shift_the_cursor_and_selection() {
+        let mut state = state(&["a", "b", "c", "d"]);
+        state.set_selected([1, 3]);
+        state.cursor = Some(3);
+
+        state.insert(2, "x");
+        assert_eq!(state.items, ["a", "b", "x", "c", "d"]);
+        assert_eq!(state.selected, BTreeSet::from([1, 4]));
+        assert_eq!(state.cursor, Some(4));
+
+        // Removing a selected item drops it, later indices move up
+        state.remove(1);
+        assert_eq!(state.items, ["a", "x", "c", "d"]);
+        assert_eq!(state.selected, BTreeSet::from([3]));
+        assert_eq!(state.cursor, Some(3));
+
+        state.remove(0);
+        assert_eq!(state.selected, BTreeSet::from([2]));
+        assert_eq!(state.cursor, Some(2));
+    }
+
+    #[test]
+    fn removing_the_last_item_clamps_the_cursor() {
+        let mut state = state(&["a", "b", "c"]);
+        state.select(Some(2));
+        state.remove(2);
+        assert_eq!(state.cursor, Some(1));
+        assert!(state.selected.is_empty());
+
+        state.remove(1);
+        state.remove(0);
+        assert!(state.items.is_empty());
+        assert_eq!(state.cursor, None);
+    }
+
+    #[test]
+    fn search_wraps_around() {
+        let mut state = state(&["apple", "banana", "avocado", "cherry"]);
+        state.select(Some(2));
+        assert_eq!(
+            press(&mut state, KeyCode::Char('A'), KeyModifiers::SHIFT),
+            Outcome::Changed
+        );
+        assert_eq!(state.cursor, Some(0));
+        assert_eq!(state.selected, BTreeSet::from([0]));
+
+        // No match leaves the cursor alone
+        assert_eq!(
+            press(&mut state, KeyCode::Char('z'), KeyModifiers::NONE),
+            Outcome::Handled
+        );
+        assert_eq!(state.cursor, Some(0));
+    }
+
+    #[test]
+    fn shift_extends_the_selection_in_multiple_mode() {
+        let mut state = state(&["a", "b", "c", "d"]);
+        state.mode = SelectionMode::Multiple;
+        assert_eq!(
+            press(&mut state, KeyCode::Down, KeyModifiers::SHIFT),
+            Outcome::Changed
+        );
+        assert_eq!(
+            press(&mut state, KeyCode::Down, KeyModifiers::SHIFT),
+            Outcome::Changed
+        );
+        assert_eq!(state.selected, BTreeSet::from([0, 1]));
+
+        // Plain arrows only move the cursor
+        assert_eq!(
+            press(&mut state, KeyCode::Down, KeyModifiers::NONE),
+            Outcome::Handled
+        );
+        assert_eq!(state.cursor, Some(2));
+        assert_eq!(state.selected, BTreeSet::from([0, 1]));
+
+        assert_eq!(
+            press(&mut state, KeyCode::End, KeyModifiers::SHIFT),
+            Outcome::Changed
+        );
+        assert_eq!(state.selected, BTreeSet::from([0, 1, 3]));
+    }
+}
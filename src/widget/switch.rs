@@ -0,0 +1,261 @@
+use crate::event::{EventContext, EventStream, EventType, UiMessage, WidgetEvent, WidgetMessage};
+use crate::style::Style;
+use crate::widget::{Widget, WidgetKind, WidgetType};
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use parking_lot::Mutex;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+    style::{Modifier, Style as RatatuiStyle},
+    text::{Line, Span},
+    widgets::{Paragraph, Widget as RatatuiWidget},
+};
+use std::any::Any;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use unicode_width::UnicodeWidthStr;
+
+/// Messages for Switch widget.
+pub enum SwitchMessage {
+    SetChecked(bool),
+    SetLabel(String),
+}
+
+impl WidgetMessage for SwitchMessage {
+    fn apply(self: Box<Self>, widget: &mut dyn Widget) {
+        if let Some(switch) = widget.as_any_mut().downcast_mut::<Switch>() {
+            match *self {
+                SwitchMessage::SetChecked(checked) => *switch.checked.lock() = checked,
+                SwitchMessage::SetLabel(label) => switch.label = label,
+            }
+        }
+    }
+}
+
+/// Events from a Switch widget, see `SwitchHandle::events`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SwitchEvent {
+    Changed(bool),
+    Focused,
+    Blurred,
+}
+
+impl SwitchEvent {
+    fn from_ctx(ctx: &EventContext) -> Option<Self> {
+        Some(match ctx.event_type {
+            EventType::Change => SwitchEvent::Changed(ctx.value.as_deref()?.parse().ok()?),
+            EventType::Focus => SwitchEvent::Focused,
+            EventType::Blur => SwitchEvent::Blurred,
+            _ => return None,
+        })
+    }
+}
+
+/// Handle for controlling a Switch widget.
+#[derive(Clone)]
+pub struct SwitchHandle {
+    id: String,
+    style: crate::style::Style,
+    ui_tx: mpsc::Sender<UiMessage>,
+    checked: Arc<Mutex<bool>>,
+}
+
+impl crate::document::WidgetHandle for SwitchHandle {
+    fn id(&self) -> &str {
+        &self.id
+    }
+    fn style(&self) -> &crate::style::Style {
+        &self.style
+    }
+    fn ui_tx(&self) -> &mpsc::Sender<UiMessage> {
+        &self.ui_tx
+    }
+}
+
+impl SwitchHandle {
+    /// Whether the switch is on.
+    pub fn is_checked(&self) -> bool {
+        *self.checked.lock()
+    }
+
+    /// Turn the switch on or off. Doesn't fire `Change`.
+    pub fn set_checked(&self, checked: bool) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        *self.checked.lock() = checked;
+        self.ui_tx.try_send(UiMessage::WidgetMessage {
+            id: self.id.clone(),
+            message: Box::new(SwitchMessage::SetChecked(checked)),
+        })?;
+        Ok(())
+    }
+
+    /// Set the label.
+    pub fn set_label(
+        &self,
+        label: impl Into<String>,
+    ) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.ui_tx.try_send(UiMessage::WidgetMessage {
+            id: self.id.clone(),
+            message: Box::new(SwitchMessage::SetLabel(label.into())),
+        })?;
+        Ok(())
+    }
+
+    /// Stream of this switch's change and focus events.
+    pub fn events(&self) -> Result<EventStream<SwitchEvent>, mpsc::error::TrySendError<UiMessage>> {
+        EventStream::subscribe(
+            &self.id,
+            &self.ui_tx,
+            &[EventType::Change, EventType::Focus, EventType::Blur],
+            SwitchEvent::from_ctx,
+        )
+    }
+}
+
+impl WidgetType for Switch {
+    type Handle = SwitchHandle;
+
+    fn kind() -> WidgetKind {
+        WidgetKind::Switch
+    }
+
+    fn create_handle(
+        &self,
+        id: String,
+        ui_tx: mpsc::Sender<UiMessage>,
+        style: crate::style::Style,
+    ) -> Self::Handle {
+        SwitchHandle {
+            id,
+            style,
+            ui_tx,
+            checked: Arc::clone(&self.checked),
+        }
+    }
+}
+
+/// An on/off toggle switch, flipped with Space, Enter, Left/Right or a click.
+/// Clicks flip it on release, so moving off the switch first cancels.
+///
+/// Renders as `[  ON] label` or `[OFF ] label`; `Change` events carry `"true"` or `"false"`.
+pub struct Switch {
+    label: String,
+    checked: Arc<Mutex<bool>>,
+    disabled: bool,
+    /// Events raised by input, until taken by the render loop.
+    events: Vec<WidgetEvent>,
+}
+
+impl Switch {
+    pub fn new<S: Into<String>>(label: S) -> Self {
+        Switch {
+            label: label.into(),
+            checked: Arc::new(Mutex::new(false)),
+            disabled: false,
+            events: Vec::new(),
+        }
+    }
+
+    /// Start on or off.
+    pub fn checked(self, checked: bool) -> Self {
+        *self.checked.lock() = checked;
+        self
+    }
+
+    /// Start disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn is_checked(&self) -> bool {
+        *self.checked.lock()
+    }
+
+    pub fn set_checked(&mut self, checked: bool) {
+        *self.checked.lock() = checked;
+    }
+
+    /// Set the state from user input, firing `Change` if it differs.
+    fn turn(&mut self, checked: bool) {
+        let changed = std::mem::replace(&mut *self.checked.lock(), checked) != checked;
+        if changed {
+            self.events.push(WidgetEvent::Change(checked.to_string()));
+        }
+    }
+}
+
+impl Default for Switch {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+impl Widget for Switch {
+    fn render(&self, buffer: &mut Buffer, area: Rect, style: &Style, is_focused: bool) {
+        // Apply padding
+        let inner_area = style.shrink(area);
+
+        let checked = *self.checked.lock();
+        let track = if checked { "[  ON]" } else { "[OFF ]" };
+        let mut track_style = RatatuiStyle::default();
+        if checked {
+            track_style = track_style.add_modifier(Modifier::BOLD);
+        }
+        if style.disabled {
+            track_style = track_style.add_modifier(Modifier::DIM);
+        } else if is_focused {
+            track_style = track_style.add_modifier(Modifier::REVERSED);
+        }
+        let line = Line::from(vec![
+            Span::styled(track, track_style),
+            Span::raw(" "),
+            Span::raw(self.label.as_str()),
+        ]);
+        Paragraph::new(line).render(inner_area, buffer);
+    }
+
+    fn node_style_hint(&self) -> Option<Style> {
+        Some(
+            Style::new()
+                .no_border()
+                .focusable(true)
+                .disabled(self.disabled),
+        )
+    }
+
+    fn content_size(&self, _area: Rect) -> (u16, u16) {
+        // "[  ON] " plus the label
+        (self.label.width() as u16 + 7, 1)
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Char(' ') | KeyCode::Enter => {
+                let checked = !self.is_checked();
+                self.turn(checked);
+            }
+            KeyCode::Right => self.turn(true),
+            KeyCode::Left => self.turn(false),
+            _ => return false,
+        }
+        true
+    }
+
+    fn handle_mouse(&mut self, mouse: MouseEvent, area: Rect, _style: &Style) -> bool {
+        let inside = area.contains(Position::new(mouse.column, mouse.row));
+        if mouse.kind == MouseEventKind::Up(MouseButton::Left) && inside {
+            let checked = !self.is_checked();
+            self.turn(checked);
+            return true;
+        }
+        false
+    }
+
+    fn take_events(&mut self) -> Vec<WidgetEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -0,0 +1,902 @@
+use crate::event::{
+    CustomEvent, EventContext, EventStream, EventType, UiMessage, WidgetEvent, WidgetMessage,
+};
+use crate::style::{BorderType, Style};
+use crate::widget::selection::{Outcome, SelectionMode, SelectionState};
+use crate::widget::{Widget, WidgetKind, WidgetType};
+use crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use parking_lot::Mutex;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Flex, Layout, Rect},
+    style::{Modifier, Style as RatatuiStyle},
+    widgets::{Block, Cell, Row, Table as RatatuiTable, Widget as RatatuiWidget},
+};
+use std::any::Any;
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use unicode_width::UnicodeWidthStr;
+
+/// Name of the custom event fired when a header is clicked; the payload is
+/// the column index (`usize`).
+pub const HEADER_CLICK: &str = "header-click";
+
+/// Spaces between columns.
+const COLUMN_SPACING: u16 = 1;
+
+/// How wide a column is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColumnWidth {
+    /// Exactly this many cells.
+    Fixed(u16),
+    /// A percentage of the table's width.
+    Percent(u16),
+    /// As wide as the widest cell or the title.
+    #[default]
+    Auto,
+}
+
+/// Sort direction of a column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+/// A table column.
+#[derive(Debug, Clone)]
+pub struct Column {
+    pub title: String,
+    pub width: ColumnWidth,
+    /// Whether clicking the header sorts by this column.
+    pub sortable: bool,
+}
+
+impl Column {
+    pub fn new<S: Into<String>>(title: S) -> Self {
+        Column {
+            title: title.into(),
+            width: ColumnWidth::Auto,
+            sortable: true,
+        }
+    }
+
+    pub fn width(mut self, width: ColumnWidth) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+}
+
+/// Rows, selection, sort and scroll position, shared between a Table and its handle.
+#[derive(Debug, Default)]
+struct TableState {
+    rows: SelectionState<Vec<String>>,
+    sort: Option<(usize, SortOrder)>,
+    /// Widest cell of each column, kept until the rows change.
+    cell_widths: Option<Vec<usize>>,
+}
+
+impl TableState {
+    /// Reorder the rows by the current sort column. Selection and cursor
+    /// follow their rows; equal rows keep their order.
+    fn apply_sort(&mut self) {
+        let Some(sort) = self.sort else {
+            return;
+        };
+        let rows = &self.rows.items;
+        let mut permutation: Vec<usize> = (0..rows.len()).collect();
+        permutation.sort_by(|&a, &b| compare_rows(sort, &rows[a], &rows[b]));
+        self.rows.reorder(&permutation);
+    }
+
+    /// Add rows at the end, or each at its place in the sort order.
+    fn append(&mut self, rows: Vec<Vec<String>>) {
+        if let Some(widths) = &mut self.cell_widths {
+            for row in &rows {
+                grow_widths(widths, row);
+            }
+        }
+        let Some(sort) = self.sort else {
+            self.rows.items.extend(rows);
+            return;
+        };
+        for row in rows {
+            // After any equal rows, where a stable sort would put it
+            let index = self
+                .rows
+                .items
+                .partition_point(|other| compare_rows(sort, other, &row) != Ordering::Greater);
+            self.rows.insert(index, row);
+        }
+    }
+
+    /// Forget the cell widths and restore the sort order after rows were
+    /// edited or replaced.
+    fn rows_changed(&mut self) {
+        self.cell_widths = None;
+        self.apply_sort();
+    }
+
+    fn cell_widths(&mut self) -> &[usize] {
+        self.cell_widths.get_or_insert_with(|| {
+            let mut widths = Vec::new();
+            for row in &self.rows.items {
+                grow_widths(&mut widths, row);
+            }
+            widths
+        })
+    }
+}
+
+/// Widen `widths` to fit a row's cells.
+fn grow_widths(widths: &mut Vec<usize>, row: &[String]) {
+    if widths.len() < row.len() {
+        widths.resize(row.len(), 0);
+    }
+    for (width, cell) in widths.iter_mut().zip(row) {
+        *width = (*width).max(cell.width());
+    }
+}
+
+/// Compare two rows by a sort column.
+fn compare_rows(sort: (usize, SortOrder), a: &[String], b: &[String]) -> Ordering {
+    let (column, order) = sort;
+    let a = a.get(column).map_or("", String::as_str);
+    let b = b.get(column).map_or("", String::as_str);
+    match order {
+        SortOrder::Ascending => compare_cells(a, b),
+        SortOrder::Descending => compare_cells(b, a),
+    }
+}
+
+/// Compare two cells: numbers numerically and before text, text lexically.
+fn compare_cells(a: &str, b: &str) -> Ordering {
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.total_cmp(&b),
+        (Ok(_), Err(_)) => Ordering::Less,
+        (Err(_), Ok(_)) => Ordering::Greater,
+        (Err(_), Err(_)) => a.cmp(b),
+    }
+}
+
+/// Messages for Table widget.
+pub enum TableMessage {
+    Append(Vec<Vec<String>>),
+    Insert(usize, Vec<String>),
+    Remove(usize),
+    Update(usize, Vec<String>),
+    SetRows(Vec<Vec<String>>),
+    SetColumns(Vec<Column>),
+    /// Move the cursor to a row and select it (or clear the selection).
+    Select(Option<usize>),
+    SortBy(Option<(usize, SortOrder)>),
+}
+
+impl WidgetMessage for TableMessage {
+    fn apply(self: Box<Self>, widget: &mut dyn Widget) {
+        if let Some(table) = widget.as_any_mut().downcast_mut::<Table>() {
+            let mut state = table.state.lock();
+            // Only `Select` leaves the selection to the caller
+            let report = !matches!(*self, TableMessage::Select(_));
+            let selected = state.rows.selected.clone();
+            match *self {
+                TableMessage::Append(rows) => state.append(rows),
+                TableMessage::Insert(index, row) => {
+                    state.rows.insert(index, row);
+                    state.rows_changed();
+                }
+                TableMessage::Remove(index) => {
+                    state.rows.remove(index);
+                    state.cell_widths = None;
+                }
+                TableMessage::Update(index, row) => {
+                    state.rows.update(index, row);
+                    state.rows_changed();
+                }
+                TableMessage::SetRows(rows) => {
+                    state.rows.set_items(rows);
+                    state.rows_changed();
+                }
+                TableMessage::SetColumns(columns) => table.columns = columns,
+                TableMessage::Select(index) => state.rows.select(index),
+                TableMessage::SortBy(sort) => {
+                    state.sort = sort;
+                    state.apply_sort();
+                }
+            }
+            // Selected rows that moved or went away get new indices
+            if report && state.rows.selected != selected {
+                let value = selection_value(&state.rows.selected);
+                table.events.push(WidgetEvent::Change(value));
+            }
+        }
+    }
+}
+
+/// A selection as a `Change` value: indices separated by commas.
+fn selection_value(selected: &BTreeSet<usize>) -> String {
+    selected
+        .iter()
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Events from a Table widget, see `TableHandle::events`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TableEvent {
+    /// The selection changed; carries the selected row indices.
+    SelectionChanged(Vec<usize>),
+    /// Enter was pressed on a row.
+    Activated(usize),
+    /// A column header was clicked.
+    HeaderClicked(usize),
+    Focused,
+    Blurred,
+}
+
+impl TableEvent {
+    fn from_ctx(ctx: &EventContext) -> Option<Self> {
+        let value = || ctx.value.clone().unwrap_or_default();
+        Some(match &ctx.event_type {
+            EventType::Change => TableEvent::SelectionChanged(
+                value().split(',').filter_map(|i| i.parse().ok()).collect(),
+            ),
+            EventType::Submit => TableEvent::Activated(value().parse().ok()?),
+            EventType::Custom(name) if name == HEADER_CLICK => {
+                TableEvent::HeaderClicked(*ctx.payload::<usize>()?)
+            }
+            EventType::Focus => TableEvent::Focused,
+            EventType::Blur => TableEvent::Blurred,
+            _ => return None,
+        })
+    }
+}
+
+/// Handle for controlling a Table widget.
+///
+/// Row indices are in display order, i.e. after sorting. When sorting,
+/// inserting or removing rows moves selected rows to new indices, `Change`
+/// fires with the new indices. Changes are applied by the render loop before
+/// the next frame; the getters read the table's live state.
+#[derive(Clone)]
+pub struct TableHandle {
+    id: String,
+    style: crate::style::Style,
+    ui_tx: mpsc::Sender<UiMessage>,
+    state: Arc<Mutex<TableState>>,
+}
+
+impl crate::document::WidgetHandle for TableHandle {
+    fn id(&self) -> &str {
+        &self.id
+    }
+    fn style(&self) -> &crate::style::Style {
+        &self.style
+    }
+    fn ui_tx(&self) -> &mpsc::Sender<UiMessage> {
+        &self.ui_tx
+    }
+}
+
+/// Collect a row of cells.
+fn to_row<R, S>(row: R) -> Vec<String>
+where
+    R: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    row.into_iter().map(Into::into).collect()
+}
+
+impl TableHandle {
+    fn send(&self, message: TableMessage) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.ui_tx.try_send(UiMessage::WidgetMessage {
+            id: self.id.clone(),
+            message: Box::new(message),
+        })?;
+        Ok(())
+    }
+
+    /// Number of rows.
+    pub fn len(&self) -> usize {
+        self.state.lock().rows.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.state.lock().rows.items.is_empty()
+    }
+
+    /// Get a row.
+    pub fn row(&self, index: usize) -> Option<Vec<String>> {
+        self.state.lock().rows.items.get(index).cloned()
+    }
+
+    /// Get all rows.
+    pub fn rows(&self) -> Vec<Vec<String>> {
+        self.state.lock().rows.items.clone()
+    }
+
+    /// Indices of the selected rows, in order.
+    pub fn selected(&self) -> Vec<usize> {
+        self.state.lock().rows.selected.iter().copied().collect()
+    }
+
+    /// Index of the row under the cursor.
+    pub fn cursor(&self) -> Option<usize> {
+        self.state.lock().rows.cursor
+    }
+
+    /// The column and order the rows are sorted by.
+    pub fn sort(&self) -> Option<(usize, SortOrder)> {
+        self.state.lock().sort
+    }
+
+    /// Add a row at the end (or in sort order).
+    pub fn push_row<R, S>(&self, row: R) -> Result<(), mpsc::error::TrySendError<UiMessage>>
+    where
+        R: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.send(TableMessage::Append(vec![to_row(row)]))
+    }
+
+    /// Add rows at the end (or in sort order).
+    pub fn extend_rows<I, R, S>(&self, rows: I) -> Result<(), mpsc::error::TrySendError<UiMessage>>
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.send(TableMessage::Append(rows.into_iter().map(to_row).collect()))
+    }
+
+    /// Insert a row before `index`; selection and cursor stay on their rows.
+    pub fn insert_row<R, S>(
+        &self,
+        index: usize,
+        row: R,
+    ) -> Result<(), mpsc::error::TrySendError<UiMessage>>
+    where
+        R: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.send(TableMessage::Insert(index, to_row(row)))
+    }
+
+    /// Remove a row; selection and cursor stay on their rows.
+    pub fn remove_row(&self, index: usize) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.send(TableMessage::Remove(index))
+    }
+
+    /// Replace a row's cells.
+    pub fn update_row<R, S>(
+        &self,
+        index: usize,
+        row: R,
+    ) -> Result<(), mpsc::error::TrySendError<UiMessage>>
+    where
+        R: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.send(TableMessage::Update(index, to_row(row)))
+    }
+
+    /// Replace all rows. Selected indices past the new end are dropped.
+    pub fn set_rows<I, R, S>(&self, rows: I) -> Result<(), mpsc::error::TrySendError<UiMessage>>
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.send(TableMessage::SetRows(
+            rows.into_iter().map(to_row).collect(),
+        ))
+    }
+
+    /// Remove all rows.
+    pub fn clear(&self) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.send(TableMessage::SetRows(Vec::new()))
+    }
+
+    /// Replace the columns.
+    pub fn set_columns(
+        &self,
+        columns: Vec<Column>,
+    ) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.send(TableMessage::SetColumns(columns))
+    }
+
+    /// Select one row and move the cursor to it, or clear the selection.
+    /// Doesn't fire `Change`.
+    pub fn select(&self, index: Option<usize>) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.send(TableMessage::Select(index))
+    }
+
+    /// Sort by a column, or stop sorting (rows keep their current order).
+    pub fn sort_by(
+        &self,
+        sort: Option<(usize, SortOrder)>,
+    ) -> Result<(), mpsc::error::TrySendError<UiMessage>> {
+        self.send(TableMessage::SortBy(sort))
+    }
+
+    /// Stream of this table's selection, activation, header and focus events.
+    pub fn events(&self) -> Result<EventStream<TableEvent>, mpsc::error::TrySendError<UiMessage>> {
+        EventStream::subscribe(
+            &self.id,
+            &self.ui_tx,
+            &[
+                EventType::Change,
+                EventType::Submit,
+                EventType::Custom(HEADER_CLICK.into()),
+                EventType::Focus,
+                EventType::Blur,
+            ],
+            TableEvent::from_ctx,
+        )
+    }
+}
+
+impl WidgetType for Table {
+    type Handle = TableHandle;
+
+    fn kind() -> WidgetKind {
+        WidgetKind::Table
+    }
+
+    fn create_handle(
+        &self,
+        id: String,
+        ui_tx: mpsc::Sender<UiMessage>,
+        style: crate::style::Style,
+    ) -> Self::Handle {
+        TableHandle {
+            id,
+            style,
+            ui_tx,
+            state: Arc::clone(&self.state),
+        }
+    }
+}
+
+/// A table with a sticky header row and selectable, sortable rows.
+///
+/// Only the visible rows are drawn. Clicking a sortable header sorts by that
+/// column (again to reverse) and fires the `"header-click"` custom event.
+/// Rows are selected like in a `List`, and typing jumps to the next row whose
+/// sort column (or first column) starts with the typed text. `Change` events
+/// carry the selected row indices separated by commas, `Submit` (Enter) the
+/// cursor's row index.
+pub struct Table {
+    columns: Vec<Column>,
+    state: Arc<Mutex<TableState>>,
+    border_type: Option<BorderType>,
+    /// Events raised by input, until taken by the render loop.
+    events: Vec<WidgetEvent>,
+}
+
+impl Table {
+    pub fn new(columns: Vec<Column>) -> Self {
+        Table {
+            columns,
+            state: Arc::new(Mutex::new(TableState::default())),
+            border_type: None,
+            events: Vec::new(),
+        }
+    }
+
+    /// Start with these rows.
+    pub fn rows<I, R, S>(self, rows: I) -> Self
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        {
+            let mut state = self.state.lock();
+            state.rows.set_items(rows.into_iter().map(to_row).collect());
+            state.rows_changed();
+        }
+        self
+    }
+
+    /// Set the selection mode.
+    pub fn selection_mode(self, mode: SelectionMode) -> Self {
+        self.state.lock().rows.mode = mode;
+        self
+    }
+
+    /// Start sorted by a column.
+    pub fn sorted_by(self, column: usize, order: SortOrder) -> Self {
+        {
+            let mut state = self.state.lock();
+            state.sort = Some((column, order));
+            state.apply_sort();
+        }
+        self
+    }
+
+    /// Create a table with border.
+    pub fn bordered(mut self, border_type: BorderType) -> Self {
+        self.border_type = Some(border_type);
+        self
+    }
+
+    pub fn get_selected(&self) -> Vec<usize> {
+        self.state.lock().rows.selected.iter().copied().collect()
+    }
+
+    fn block(&self, style: &Style, is_focused: bool) -> Block<'static> {
+        let Some(border_type) = self.border_type else {
+            return Block::default();
+        };
+        let mut block = Block::default()
+            .borders(style.border_sides.into())
+            .border_type(match border_type {
+                BorderType::Plain => ratatui::widgets::BorderType::Plain,
+                BorderType::Rounded => ratatui::widgets::BorderType::Rounded,
+                BorderType::Double => ratatui::widgets::BorderType::Double,
+                BorderType::Thick => ratatui::widgets::BorderType::Thick,
+            });
+        if let Some(color) = style.border_color_for(is_focused) {
+            block = block.border_style(RatatuiStyle::default().fg(color.into()));
+        }
+        block
+    }
+
+    /// Header title with the sort indicator.
+    fn title(&self, column: usize, sort: Option<(usize, SortOrder)>) -> String {
+        let title = &self.columns[column].title;
+        match sort {
+            Some((sorted, SortOrder::Ascending)) if sorted == column => format!("{} ▲", title),
+            Some((sorted, SortOrder::Descending)) if sorted == column => format!("{} ▼", title),
+            _ => title.clone(),
+        }
+    }
+
+    /// Width constraints; auto columns fit the widest cell of any row.
+    fn constraints(&self, state: &mut TableState) -> Vec<Constraint> {
+        let widths = state.cell_widths();
+        self.columns
+            .iter()
+            .enumerate()
+            .map(|(i, column)| match column.width {
+                ColumnWidth::Fixed(width) => Constraint::Length(width),
+                ColumnWidth::Percent(percent) => Constraint::Percentage(percent),
+                ColumnWidth::Auto => {
+                    // Leave room for the sort indicator
+                    let title = column.title.width() + if column.sortable { 2 } else { 0 };
+                    let cells = widths.get(i).copied().unwrap_or(0);
+                    Constraint::Length(title.max(cells).min(u16::MAX as usize) as u16)
+                }
+            })
+            .collect()
+    }
+
+    /// Column under an x position, laid out the same way ratatui's Table does.
+    fn column_at(&self, state: &mut TableState, area: Rect, x: u16) -> Option<usize> {
+        Layout::horizontal(self.constraints(state))
+            .flex(Flex::Start)
+            .spacing(COLUMN_SPACING)
+            .split(area)
+            .iter()
+            .position(|rect| x >= rect.x && x < rect.x + rect.width)
+    }
+
+    /// Queue the events for what a key or click did.
+    fn dispatch(&mut self, outcome: Outcome) -> bool {
+        match outcome {
+            Outcome::Ignored => return false,
+            Outcome::Handled => {}
+            Outcome::Changed => {
+                let value = selection_value(&self.state.lock().rows.selected);
+                self.events.push(WidgetEvent::Change(value));
+            }
+            Outcome::Activated(index) => self.events.push(WidgetEvent::Submit(index.to_string())),
+        }
+        true
+    }
+
+    /// Sort by a clicked header (reversing if already sorted by it) and fire
+    /// the header click event, after `Change` if selected rows moved.
+    fn click_header(&mut self, column: usize) {
+        if self.columns[column].sortable {
+            let mut state = self.state.lock();
+            let order = match state.sort {
+                Some((sorted, SortOrder::Ascending)) if sorted == column => SortOrder::Descending,
+                _ => SortOrder::Ascending,
+            };
+            let selected = state.rows.selected.clone();
+            state.sort = Some((column, order));
+            state.apply_sort();
+            state.rows.ensure_visible();
+            if state.rows.selected != selected {
+                let value = selection_value(&state.rows.selected);
+                self.events.push(WidgetEvent::Change(value));
+            }
+        }
+        self.events.push(WidgetEvent::Custom(
+            CustomEvent::new(HEADER_CLICK).with_payload(column),
+        ));
+    }
+}
+
+impl Widget for Table {
+    fn render(&self, buffer: &mut Buffer, area: Rect, style: &Style, is_focused: bool) {
+        // Apply padding
+        let inner_area = style.shrink(area);
+        let block = self.block(style, is_focused);
+        let table_area = block.inner(inner_area);
+        block.render(inner_area, buffer);
+
+        let mut state = self.state.lock();
+        // One row for the header, and only the visible rows are handed to ratatui
+        let visible = state
+            .rows
+            .layout(table_area.height.saturating_sub(1) as usize);
+        let constraints = self.constraints(&mut state);
+
+        let header =
+            Row::new((0..self.columns.len()).map(|i| Cell::from(self.title(i, state.sort))))
+                .style(RatatuiStyle::default().add_modifier(Modifier::BOLD));
+
+        let rows = visible.map(|index| {
+            let mut row_style = RatatuiStyle::default();
+            if style.disabled {
+                row_style = row_style.add_modifier(Modifier::DIM);
+            } else if is_focused && state.rows.cursor == Some(index) {
+                row_style = row_style.add_modifier(Modifier::REVERSED);
+            } else if state.rows.selected.contains(&index) {
+                row_style = row_style.add_modifier(Modifier::BOLD);
+            }
+            Row::new(
+                state.rows.items[index]
+                    .iter()
+                    .map(|cell| Cell::from(cell.as_str())),
+            )
+            .style(row_style)
+        });
+
+        let table = RatatuiTable::new(rows, constraints)
+            .header(header)
+            .column_spacing(COLUMN_SPACING)
+            .flex(Flex::Start);
+        RatatuiWidget::render(table, table_area, buffer);
+    }
+
+    fn node_style_hint(&self) -> Option<Style> {
+        let style = match self.border_type {
+            Some(border_type) => Style::new().border(border_type),
+            None => Style::new().no_border(),
+        };
+        Some(style.focusable(true))
+    }
+
+    fn content_size(&self, _area: Rect) -> (u16, u16) {
+        let mut state = self.state.lock();
+        let widths: u16 = self
+            .constraints(&mut state)
+            .iter()
+            .map(|constraint| match constraint {
+                Constraint::Length(width) => *width,
+                _ => 0,
+            })
+            .sum();
+        let spacing = self.columns.len().saturating_sub(1) as u16 * COLUMN_SPACING;
+        let border = if self.border_type.is_some() { 2 } else { 0 };
+        let height = (state.rows.items.len() + 1).min(u16::MAX as usize) as u16;
+        (
+            widths.saturating_add(spacing + border),
+            height.saturating_add(border),
+        )
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let outcome = {
+            let mut state = self.state.lock();
+            // Type-to-search looks at the sort column
+            let column = state.sort.map_or(0, |(column, _)| column);
+            state
+                .rows
+                .handle_key(key, |row| row.get(column).map_or("", String::as_str))
+        };
+        self.dispatch(outcome)
+    }
+
+    fn handle_mouse(&mut self, mouse: MouseEvent, area: Rect, style: &Style) -> bool {
+        let table_area = self.block(style, false).inner(style.shrink(area));
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let Some(row) = mouse.row.checked_sub(table_area.y) else {
+                    return false;
+                };
+                if row >= table_area.height {
+                    return false;
+                }
+                if row == 0 {
+                    let column = self.column_at(&mut self.state.lock(), table_area, mouse.column);
+                    return match column {
+                        Some(column) => {
+                            self.click_header(column);
+                            true
+                        }
+                        None => false,
+                    };
+                }
+                let outcome = {
+                    let mut state = self.state.lock();
+                    let index = state.rows.offset + row as usize - 1;
+                    state.rows.click(index)
+                };
+                self.dispatch(outcome)
+            }
+            // The wheel scrolls the body without moving the cursor
+            MouseEventKind::ScrollUp => self.state.lock().rows.wheel(false),
+            MouseEventKind::ScrollDown => self.state.lock().rows.wheel(true),
+            _ => false,
+        }
+    }
+
+    fn take_events(&mut self) -> Vec<WidgetEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(cells: &[&str]) -> TableState {
+        let mut state = TableState {
+            sort: Some((0, SortOrder::Ascending)),
+            ..TableState::default()
+        };
+        state
+            .rows
+            .set_items(cells.iter().map(|cell| vec![cell.to_string()]).collect());
+        state.apply_sort();
+        state
+    }
+
+    fn column(state: &TableState) -> Vec<&str> {
+        state.rows.items.iter().map(|row| row[0].as_str()).collect()
+    }
+
+    #[test]
+    fn numbers_sort_before_text() {
+        let state = state(&["b", "10", "a", "9", "", "2.5"]);
+        assert_eq!(column(&state), ["2.5", "9", "10", "", "a", "b"]);
+    }
+
+    #[test]
+    fn appended_rows_keep_the_sort_and_selection() {
+        let mut state = state(&["1", "3", "5"]);
+        state.rows.select(Some(1));
+        state.append(vec![vec!["4".into()], vec!["0".into()], vec!["x".into()]]);
+        assert_eq!(column(&state), ["0", "1", "3", "4", "5", "x"]);
+        assert_eq!(state.rows.cursor, Some(2));
+        assert_eq!(state.rows.selected, BTreeSet::from([2]));
+    }
+
+    fn table() -> Table {
+        Table::new(vec![
+            Column::new("Name").width(ColumnWidth::Fixed(6)),
+            Column::new("Size").width(ColumnWidth::Fixed(4)),
+        ])
+        .rows([["b", "2"], ["c", "1"], ["a", "3"]])
+    }
+
+    /// Values of the `Change` events raised so far.
+    fn changes(table: &mut Table) -> Vec<String> {
+        table
+            .take_events()
+            .into_iter()
+            .filter_map(|event| match event {
+                WidgetEvent::Change(value) => Some(value),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn apply(table: &mut Table, message: TableMessage) {
+        Box::new(message).apply(table);
+    }
+
+    fn click(table: &mut Table, area: Rect, row: u16) -> bool {
+        let mouse = MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: 1,
+            row,
+            modifiers: crossterm::event::KeyModifiers::NONE,
+        };
+        let style = table.node_style_hint().unwrap();
+        table.handle_mouse(mouse, area, &style)
+    }
+
+    #[test]
+    fn header_click_reports_moved_selection() {
+        let mut table = table();
+        table.state.lock().rows.select(Some(0));
+
+        table.click_header(0);
+        assert_eq!(column(&table.state.lock()), ["a", "b", "c"]);
+        let events = table.take_events();
+        assert!(matches!(&events[0], WidgetEvent::Change(value) if value == "1"));
+        assert!(matches!(&events[1], WidgetEvent::Custom(event) if event.name == HEADER_CLICK));
+
+        // Reversing keeps "b" in the middle: nothing to report
+        table.click_header(0);
+        assert_eq!(column(&table.state.lock()), ["c", "b", "a"]);
+        assert!(changes(&mut table).is_empty());
+    }
+
+    #[test]
+    fn column_at_follows_widths_and_spacing() {
+        let table = table();
+        let mut state = table.state.lock();
+        let area = Rect::new(2, 0, 20, 5);
+        let columns: Vec<_> = [2, 7, 8, 9, 12, 13]
+            .iter()
+            .map(|&x| table.column_at(&mut state, area, x))
+            .collect();
+        assert_eq!(columns, [Some(0), Some(0), None, Some(1), Some(1), None]);
+    }
+
+    #[test]
+    fn clicks_map_to_rows_below_the_header() {
+        let mut table = table();
+        let area = Rect::new(0, 10, 20, 3);
+        {
+            let mut state = table.state.lock();
+            state.rows.viewport = 2;
+            state.rows.offset = 1;
+        }
+
+        assert!(click(&mut table, area, 11));
+        assert_eq!(table.get_selected(), [1]);
+        assert!(click(&mut table, area, 12));
+        assert_eq!(table.get_selected(), [2]);
+        assert_eq!(changes(&mut table), ["1", "2"]);
+
+        // The header row sorts instead
+        assert!(click(&mut table, area, 10));
+        assert_eq!(table.state.lock().sort, Some((0, SortOrder::Ascending)));
+    }
+
+    #[test]
+    fn row_changes_report_moved_selection() {
+        let mut table = table().sorted_by(0, SortOrder::Ascending);
+        apply(&mut table, TableMessage::Select(Some(1)));
+        assert!(changes(&mut table).is_empty());
+
+        apply(&mut table, TableMessage::Remove(0));
+        assert_eq!(column(&table.state.lock()), ["b", "c"]);
+        assert_eq!(changes(&mut table), ["0"]);
+
+        apply(
+            &mut table,
+            TableMessage::Update(0, vec!["z".into(), "2".into()]),
+        );
+        assert_eq!(column(&table.state.lock()), ["c", "z"]);
+        assert_eq!(changes(&mut table), ["1"]);
+
+        apply(&mut table, TableMessage::Append(vec![vec!["a".into()]]));
+        assert_eq!(table.get_selected(), [2]);
+        assert_eq!(changes(&mut table), ["2"]);
+
+        // Rows added after the selection don't move it
+        apply(&mut table, TableMessage::Append(vec![vec!["zz".into()]]));
+        assert!(changes(&mut table).is_empty());
+    }
+}
@@ -77,16 +77,16 @@ impl WidgetType for Text {
     }
 
     fn create_handle(
+        &self,
         id: String,
         ui_tx: mpsc::Sender<UiMessage>,
         style: crate::style::Style,
     ) -> Self::Handle {
-        let content = Arc::new(Mutex::new(String::new()));
         TextHandle {
             id,
             style,
             ui_tx,
-            content: Arc::clone(&content),
+            content: Arc::clone(&self.content),
         }
     }
 }
@@ -150,7 +150,7 @@ impl Widget for Text {
             max_line_width = max_line_width.max(line_width);
 
             // Calculate how many terminal lines this line needs
-            let wrapped_lines = (line_width + max_width - 1) / max_width;
+            let wrapped_lines = line_width.div_ceil(max_width);
             total_lines += wrapped_lines.max(1);
         }
 
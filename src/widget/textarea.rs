@@ -1,4 +1,4 @@
-use crate::event::{UiMessage, WidgetMessage};
+use crate::event::{EventContext, EventStream, EventType, UiMessage, WidgetEvent, WidgetMessage};
 use crate::style::{BorderType, Style};
 use crate::widget::{Widget, WidgetKind, WidgetType};
 use crossterm::event::KeyEvent;
@@ -7,7 +7,8 @@ use ratatui::{
     buffer::Buffer,
     layout::Rect,
     style::{Modifier, Style as RatatuiStyle},
-    widgets::{Block, Borders, Widget as RatatuiWidget},
+    text::Line,
+    widgets::{Block, Widget as RatatuiWidget},
 };
 use ratatui_textarea::TextArea;
 use std::any::Any;
@@ -36,6 +37,30 @@ impl WidgetMessage for TextareaMessage {
     }
 }
 
+/// Events from a Textarea widget, see `TextareaHandle::events`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextareaEvent {
+    /// The value was edited; carries the new value.
+    Changed(String),
+    /// Ctrl+Enter (or Alt+Enter, Ctrl+J) was pressed; carries the value.
+    Submitted(String),
+    Focused,
+    Blurred,
+}
+
+impl TextareaEvent {
+    fn from_ctx(ctx: &EventContext) -> Option<Self> {
+        let value = || ctx.value.clone().unwrap_or_default();
+        Some(match ctx.event_type {
+            EventType::Change => TextareaEvent::Changed(value()),
+            EventType::Submit => TextareaEvent::Submitted(value()),
+            EventType::Focus => TextareaEvent::Focused,
+            EventType::Blur => TextareaEvent::Blurred,
+            _ => return None,
+        })
+    }
+}
+
 /// Handle for controlling a Textarea widget.
 #[derive(Clone)]
 pub struct TextareaHandle {
@@ -58,6 +83,23 @@ impl crate::document::WidgetHandle for TextareaHandle {
 }
 
 impl TextareaHandle {
+    /// Stream of this widget's value and focus events.
+    pub fn events(
+        &self,
+    ) -> Result<EventStream<TextareaEvent>, mpsc::error::TrySendError<UiMessage>> {
+        EventStream::subscribe(
+            &self.id,
+            &self.ui_tx,
+            &[
+                EventType::Change,
+                EventType::Submit,
+                EventType::Focus,
+                EventType::Blur,
+            ],
+            TextareaEvent::from_ctx,
+        )
+    }
+
     /// Get the current value.
     pub fn get_value(&self) -> String {
         self.textarea.lock().lines().join("\n")
@@ -93,25 +135,30 @@ impl WidgetType for Textarea {
     }
 
     fn create_handle(
+        &self,
         id: String,
         ui_tx: mpsc::Sender<UiMessage>,
         style: crate::style::Style,
     ) -> Self::Handle {
-        let textarea = Arc::new(Mutex::new(TextArea::default()));
         TextareaHandle {
             id,
             style,
             ui_tx,
-            textarea: Arc::clone(&textarea),
+            textarea: Arc::clone(&self.textarea),
         }
     }
 }
 
 /// A multi-line text input widget.
+///
+/// Enter inserts a newline. Ctrl+Enter submits where the terminal reports it;
+/// Alt+Enter and Ctrl+J submit on every terminal.
 pub struct Textarea {
     textarea: Arc<Mutex<TextArea<'static>>>,
     border_type: Option<BorderType>,
     height: u16,
+    /// Events raised by input, until taken by the render loop.
+    events: Vec<WidgetEvent>,
 }
 
 impl Textarea {
@@ -127,13 +174,14 @@ impl Textarea {
             textarea,
             border_type: None,
             height: 5,
+            events: Vec::new(),
         }
     }
 
     pub fn with_value<S: Into<String>>(value: S) -> Self {
         let textarea = Arc::new(Mutex::new({
             let mut ta = TextArea::default();
-            ta.insert_str(&value.into());
+            ta.insert_str(value.into());
             ta.set_cursor_line_style(RatatuiStyle::default());
             ta.set_cursor_style(RatatuiStyle::default().add_modifier(Modifier::REVERSED));
             ta
@@ -143,6 +191,7 @@ impl Textarea {
             textarea,
             border_type: None,
             height: 5,
+            events: Vec::new(),
         }
     }
 
@@ -164,7 +213,7 @@ impl Textarea {
 
     pub fn set_value<S: Into<String>>(&mut self, value: S) {
         let mut ta = TextArea::default();
-        ta.insert_str(&value.into());
+        ta.insert_str(value.into());
         *self.textarea.lock() = ta;
     }
 }
@@ -182,15 +231,23 @@ impl Widget for Textarea {
 
         // Show border only if needed
         let block = if self.border_type.is_some() {
-            Block::default()
-                .borders(Borders::ALL)
+            let mut block = Block::default()
+                .borders(style.border_sides.into())
                 .border_type(match self.border_type {
                     Some(BorderType::Plain) => ratatui::widgets::BorderType::Plain,
                     Some(BorderType::Rounded) => ratatui::widgets::BorderType::Rounded,
                     Some(BorderType::Double) => ratatui::widgets::BorderType::Double,
                     Some(BorderType::Thick) => ratatui::widgets::BorderType::Thick,
                     None => ratatui::widgets::BorderType::Plain,
-                })
+                });
+            if let Some(color) = style.border_color_for(is_focused) {
+                block = block.border_style(RatatuiStyle::default().fg(color.into()));
+            }
+            if let Some(title) = &style.title {
+                block =
+                    block.title_top(Line::from(title.as_str()).alignment(style.title_align.into()));
+            }
+            block
         } else {
             Block::default()
         };
@@ -215,10 +272,11 @@ impl Widget for Textarea {
 
     fn node_style_hint(&self) -> Option<Style> {
         // Return style based on border_type
-        match self.border_type {
-            Some(border_type) => Some(Style::new().border(border_type)),
-            None => Some(Style::new().no_border()),
-        }
+        let style = match self.border_type {
+            Some(border_type) => Style::new().border(border_type),
+            None => Style::new().no_border(),
+        };
+        Some(style.focusable(true))
     }
 
     fn content_size(&self, _area: Rect) -> (u16, u16) {
@@ -229,11 +287,50 @@ impl Widget for Textarea {
     }
 
     fn handle_key(&mut self, key: KeyEvent) -> bool {
-        // Enter key inserts newline in multi-line mode
+        // Leave Tab/BackTab to focus navigation
+        use crossterm::event::{KeyCode, KeyModifiers};
+        if matches!(key.code, KeyCode::Tab | KeyCode::BackTab) {
+            return false;
+        }
+
+        // Ctrl+Enter submits; plain Enter inserts a newline. Most terminals only
+        // tell Ctrl+Enter apart with keyboard enhancement, so Alt+Enter and
+        // Ctrl+J (sent by Ctrl+Enter on some terminals) submit as well.
+        let submit = match key.code {
+            KeyCode::Enter => key
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT),
+            KeyCode::Char('j') => key.modifiers.contains(KeyModifiers::CONTROL),
+            _ => false,
+        };
+        if submit {
+            self.events.push(WidgetEvent::Submit(self.value()));
+            return true;
+        }
+
+        let before = self.value();
         self.textarea.lock().input(key);
+        let after = self.value();
+        if after != before {
+            self.events.push(WidgetEvent::Change(after));
+        }
         true
     }
 
+    fn handle_paste(&mut self, text: &str) -> bool {
+        // Terminals may send CR or CRLF line endings
+        let text = text.replace("\r\n", "\n").replace('\r', "\n");
+        if !text.is_empty() {
+            self.textarea.lock().insert_str(text);
+            self.events.push(WidgetEvent::Change(self.value()));
+        }
+        true
+    }
+
+    fn take_events(&mut self) -> Vec<WidgetEvent> {
+        std::mem::take(&mut self.events)
+    }
+
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }